@@ -0,0 +1,126 @@
+//! Extracting identifiers in actix-web handlers.
+//!
+//! `Id<T>` implements `FromRequest`, reading the route's single path parameter:
+//!
+//! ```ignore
+//! async fn get_user(id: Id<User>) -> String {
+//!     id.to_string()
+//! }
+//!
+//! let app = App::new().route("/users/{id}", web::get().to(get_user));
+//! ```
+//!
+//! Failures respond with `400 Bad Request` by default. Register an [`IdConfig`] as app data
+//! to customize the response.
+
+use crate::{Error, Id, Type};
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::web::Data;
+use actix_web::{FromRequest, HttpRequest, ResponseError};
+use std::fmt::{Display, Formatter};
+use std::future::{ready, Ready};
+use std::sync::Arc;
+
+/// Error produced when an `Id<T>` cannot be extracted from the request path.
+#[derive(Debug)]
+pub enum IdError {
+    /// The route does not have exactly one path parameter.
+    Path { count: usize },
+    /// The path parameter is not a valid identifier.
+    Invalid {
+        expected: &'static str,
+        error: Error,
+    },
+}
+
+impl Display for IdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Path { count } => write!(
+                f,
+                "expected a single path parameter for the identifier, found {}",
+                count
+            ),
+            Self::Invalid { expected, error } => {
+                write!(
+                    f,
+                    "invalid `{}` identifier, expected `{}_",
+                    expected, expected
+                )?;
+
+                if let Some(environment) = crate::environment() {
+                    write!(f, "{}_", environment)?;
+                }
+
+                write!(f, "` followed by 26 base32 characters: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IdError {}
+
+impl ResponseError for IdError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Path { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::Invalid { .. } => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+/// Configuration for extracting an `Id<T>` from the request path.
+///
+/// ```ignore
+/// let app = App::new().app_data(IdConfig::default().error_handler(|err, _req| {
+///     error::InternalError::from_response(err, HttpResponse::NotFound().into()).into()
+/// }));
+/// ```
+#[derive(Clone, Default)]
+pub struct IdConfig {
+    #[allow(clippy::type_complexity)]
+    error_handler: Option<Arc<dyn Fn(IdError, &HttpRequest) -> actix_web::Error + Send + Sync>>,
+}
+
+impl IdConfig {
+    /// Set a custom error handler.
+    pub fn error_handler<F>(mut self, f: F) -> Self
+    where
+        F: Fn(IdError, &HttpRequest) -> actix_web::Error + Send + Sync + 'static,
+    {
+        self.error_handler = Some(Arc::new(f));
+        self
+    }
+}
+
+impl<T: Type> FromRequest for Id<T> {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let mut params = req.match_info().iter();
+
+        let result = match (params.next(), params.next()) {
+            (Some((_, value)), None) => Self::parse(value).map_err(|error| IdError::Invalid {
+                expected: T::PREFIX,
+                error,
+            }),
+            _ => Err(IdError::Path {
+                count: req.match_info().iter().count(),
+            }),
+        };
+
+        ready(result.map_err(|err| {
+            let error_handler = req
+                .app_data::<IdConfig>()
+                .or_else(|| req.app_data::<Data<IdConfig>>().map(Data::get_ref))
+                .and_then(|c| c.error_handler.clone());
+
+            match error_handler {
+                Some(error_handler) => error_handler(err, req),
+                None => err.into(),
+            }
+        }))
+    }
+}
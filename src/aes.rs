@@ -0,0 +1,55 @@
+use crate::{Id, Type};
+use aes::cipher::generic_array::GenericArray;
+use aes::cipher::{BlockDecrypt, BlockEncrypt, KeyInit};
+use aes::Aes128;
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Map this identifier to a stable pseudonym under the provided key.
+    ///
+    /// The value is encrypted as a single AES-128 block, so the mapping is one-to-one:
+    /// every identifier gets a distinct pseudonym, the same identifier always gets the
+    /// same pseudonym under the same key, and pseudonyms can't be linked back to real
+    /// identifiers without the key. Use [`Id::depseudonymize`] to reverse it.
+    ///
+    /// Pseudonyms of time-ordered identifiers do not preserve their ordering or
+    /// timestamps.
+    pub fn pseudonymize(self, key: &[u8; 16]) -> Self {
+        let mut block = GenericArray::from(self.to_bytes());
+        Aes128::new(key.into()).encrypt_block(&mut block);
+        Self::new(block.into())
+    }
+
+    /// Recover the identifier behind a pseudonym created with [`Id::pseudonymize`].
+    pub fn depseudonymize(self, key: &[u8; 16]) -> Self {
+        let mut block = GenericArray::from(self.to_bytes());
+        Aes128::new(key.into()).decrypt_block(&mut block);
+        Self::new(block.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    const KEY: [u8; 16] = 0x00010203_04050607_08090a0b_0c0d0e0fu128.to_be_bytes();
+
+    #[test]
+    fn pseudonym_round_trip() {
+        // The AES-128 example from FIPS-197 appendix C.1.
+        let id = Id::<User>::from(0x00112233_44556677_8899aabb_ccddeeffu128);
+        let pseudonym = id.pseudonymize(&KEY);
+
+        assert_eq!(pseudonym.to_u128(), 0x69c4e0d8_6a7b0430_d8cdb780_70b4c55a);
+        assert_eq!(pseudonym.depseudonymize(&KEY), id);
+
+        let other = id.pseudonymize(&[0xff; 16]);
+        assert_ne!(other, pseudonym);
+        assert_eq!(other.depseudonymize(&[0xff; 16]), id);
+    }
+}
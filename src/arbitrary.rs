@@ -0,0 +1,25 @@
+use crate::{Generic, Id, IdBytes, Type};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+const PREFIX_ALPHABET: &[u8; 36] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+impl<'a, T: Type + ?Sized> Arbitrary<'a> for Id<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        IdBytes::arbitrary(u).map(Self::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        IdBytes::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Generic {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.int_in_range(1..=8)?;
+        let prefix = (0..len)
+            .map(|_| u.choose(PREFIX_ALPHABET).map(|c| *c as char))
+            .collect::<Result<String>>()?;
+
+        Ok(Self::new(prefix, IdBytes::arbitrary(u)?).expect("prefix to be valid"))
+    }
+}
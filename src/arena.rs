@@ -0,0 +1,166 @@
+use crate::{Generator, Id, IdMap, Sequential, Type};
+use std::collections::hash_map::{self, Entry};
+use std::fmt::{Debug, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A store of values which mints a fresh `Id<T>` for each inserted value.
+///
+/// Identifiers come from the arena's [`Generator`], which is [`Sequential`] by default;
+/// use [`IdArena::with_generator`] with `Random` for unpredictable identifiers. Values
+/// minted by the generator that are already in use are skipped, so every insert gets an
+/// identifier that is unique within the arena.
+///
+/// ```
+/// use souvenir::{IdArena, Type};
+///
+/// struct User {
+///     name: &'static str,
+/// }
+///
+/// impl Type for User {
+///     const PREFIX: &'static str = "user";
+/// }
+///
+/// let mut users: IdArena<User> = IdArena::new();
+/// let id = users.insert(User { name: "ferris" });
+///
+/// assert_eq!(users[id].name, "ferris");
+/// assert_eq!(id.to_u128(), 1);
+/// ```
+pub struct IdArena<T: Type + ?Sized, V = T, G: Generator = Sequential> {
+    values: IdMap<T, V>,
+    generator: G,
+}
+
+impl<T: Type + ?Sized, V> IdArena<T, V> {
+    /// Create an empty arena issuing sequential identifiers.
+    pub fn new() -> Self {
+        Self::with_generator(Sequential::new())
+    }
+}
+
+impl<T: Type + ?Sized, V, G: Generator> IdArena<T, V, G> {
+    /// Create an empty arena issuing identifiers from the provided generator.
+    pub fn with_generator(generator: G) -> Self {
+        Self {
+            values: IdMap::new(),
+            generator,
+        }
+    }
+
+    /// Store a value, returning its newly minted identifier.
+    pub fn insert(&mut self, value: V) -> Id<T> {
+        self.insert_with(|_| value)
+    }
+
+    /// Store a value built from its newly minted identifier, returning the identifier.
+    pub fn insert_with(&mut self, f: impl FnOnce(Id<T>) -> V) -> Id<T> {
+        loop {
+            let id = Id::generate(&self.generator);
+
+            if let Entry::Vacant(entry) = self.values.entry(id) {
+                entry.insert(f(id));
+                return id;
+            }
+        }
+    }
+
+    /// Get the value for an identifier.
+    pub fn get(&self, id: Id<T>) -> Option<&V> {
+        self.values.get(&id)
+    }
+
+    /// Get the value for an identifier mutably.
+    pub fn get_mut(&mut self, id: Id<T>) -> Option<&mut V> {
+        self.values.get_mut(&id)
+    }
+
+    /// Remove the value for an identifier, returning it.
+    pub fn remove(&mut self, id: Id<T>) -> Option<V> {
+        self.values.remove(&id)
+    }
+
+    /// Test whether the arena holds a value for an identifier.
+    pub fn contains(&self, id: Id<T>) -> bool {
+        self.values.contains_key(&id)
+    }
+
+    /// Get the number of stored values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Test whether the arena is empty.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterate over the identifiers and values, in arbitrary order.
+    pub fn iter(&self) -> hash_map::Iter<'_, Id<T>, V> {
+        self.values.iter()
+    }
+
+    /// Iterate over the identifiers and values mutably, in arbitrary order.
+    pub fn iter_mut(&mut self) -> hash_map::IterMut<'_, Id<T>, V> {
+        self.values.iter_mut()
+    }
+
+    /// Iterate over the identifiers, in arbitrary order.
+    pub fn ids(&self) -> impl Iterator<Item = Id<T>> + '_ {
+        self.values.keys().copied()
+    }
+
+    /// Iterate over the values, in arbitrary order.
+    pub fn values(&self) -> hash_map::Values<'_, Id<T>, V> {
+        self.values.values()
+    }
+
+    /// Get the generator issuing identifiers.
+    pub fn generator(&self) -> &G {
+        &self.generator
+    }
+}
+
+impl<T: Type + ?Sized, V> Default for IdArena<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Type + ?Sized, V: Debug, G: Generator> Debug for IdArena<T, V, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.values, f)
+    }
+}
+
+impl<T: Type + ?Sized, V, G: Generator> Index<Id<T>> for IdArena<T, V, G> {
+    type Output = V;
+
+    fn index(&self, id: Id<T>) -> &V {
+        self.get(id).expect("id to be present in the arena")
+    }
+}
+
+impl<T: Type + ?Sized, V, G: Generator> IndexMut<Id<T>> for IdArena<T, V, G> {
+    fn index_mut(&mut self, id: Id<T>) -> &mut V {
+        self.get_mut(id).expect("id to be present in the arena")
+    }
+}
+
+impl<T: Type + ?Sized, V, G: Generator> IntoIterator for IdArena<T, V, G> {
+    type Item = (Id<T>, V);
+    type IntoIter = hash_map::IntoIter<Id<T>, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'a, T: Type + ?Sized, V, G: Generator> IntoIterator for &'a IdArena<T, V, G> {
+    type Item = (&'a Id<T>, &'a V);
+    type IntoIter = hash_map::Iter<'a, Id<T>, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
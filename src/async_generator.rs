@@ -0,0 +1,253 @@
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use std::sync::Mutex;
+
+use crate::{Id, IdBytes, Type};
+
+/// An asynchronous source of identifier values, for generators which fetch blocks of
+/// values or coordination state from a remote service, such as a database sequence or
+/// an etcd lease, without blocking.
+///
+/// Unlike [`Generator`](crate::Generator), fetching can fail. Remote round trips are slow,
+/// so values are requested in blocks; wrap a generator in [`Buffered`] to keep a block in
+/// memory and serve most values without awaiting the service.
+pub trait AsyncGenerator {
+    /// The error returned when values can't be fetched.
+    type Error;
+
+    /// Produce up to `count` identifier values.
+    ///
+    /// A generator may return fewer values than requested, such as the rest of a block.
+    /// Returning none when `count` is non-zero means the generator is exhausted, which
+    /// `Id::generate_async` reports as [`GenerateError::Exhausted`].
+    fn next_values(
+        &self,
+        count: usize,
+    ) -> impl Future<Output = Result<Vec<IdBytes>, Self::Error>> + Send;
+}
+
+impl<G: AsyncGenerator + Sync + ?Sized> AsyncGenerator for &G {
+    type Error = G::Error;
+
+    fn next_values(
+        &self,
+        count: usize,
+    ) -> impl Future<Output = Result<Vec<IdBytes>, Self::Error>> + Send {
+        (**self).next_values(count)
+    }
+}
+
+impl<G: AsyncGenerator + ?Sized> AsyncGenerator for std::sync::Arc<G> {
+    type Error = G::Error;
+
+    fn next_values(
+        &self,
+        count: usize,
+    ) -> impl Future<Output = Result<Vec<IdBytes>, Self::Error>> + Send {
+        (**self).next_values(count)
+    }
+}
+
+/// An error generating an identifier with [`Id::generate_async`].
+#[derive(Debug)]
+pub enum GenerateError<E> {
+    /// The generator returned no values, such as when a remote sequence has run out.
+    Exhausted,
+    /// The generator failed.
+    Generator(E),
+}
+
+impl<E: Display> Display for GenerateError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenerateError::Exhausted => write!(f, "generator produced no values"),
+            GenerateError::Generator(e) => write!(f, "generator error: {}", e),
+        }
+    }
+}
+
+impl<E: Debug + Display> std::error::Error for GenerateError<E> {}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Generate an Id<T> with the next value from the provided asynchronous generator.
+    ///
+    /// Values reserved by [`Type::RESERVED`] or rejected by [`Type::validate`] are skipped.
+    /// A single value is requested at a time, and any further values the generator returns
+    /// are discarded, so wrap generators which allocate whole blocks remotely in a
+    /// [`Buffered`] adapter rather than allocating a block per identifier.
+    pub async fn generate_async<G: AsyncGenerator + ?Sized>(
+        generator: &G,
+    ) -> Result<Self, GenerateError<G::Error>> {
+        loop {
+            let values = generator
+                .next_values(1)
+                .await
+                .map_err(GenerateError::Generator)?;
+            let value = values.into_iter().next().ok_or(GenerateError::Exhausted)?;
+            let id = Self::new(value);
+
+            if id.is_issuable() {
+                crate::hooks::generated::<T>(core::any::type_name::<G>(), id.as_bytes());
+
+                return Ok(id);
+            }
+        }
+    }
+}
+
+/// An adapter keeping a block of values from an [`AsyncGenerator`] in memory.
+///
+/// Values are served from the buffer, and a block of `size` values is only fetched once
+/// it is empty, so most calls complete without awaiting the inner generator. A buffer can
+/// be shared between tasks; if several find it empty at once they each fetch a block, so
+/// values are unique but may be handed out slightly out of order.
+#[derive(Debug)]
+pub struct Buffered<G: AsyncGenerator> {
+    generator: G,
+    size: usize,
+    buffer: Mutex<VecDeque<IdBytes>>,
+}
+
+impl<G: AsyncGenerator> Buffered<G> {
+    /// Create an adapter fetching blocks of `size` values from `generator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn new(generator: G, size: usize) -> Self {
+        assert!(size > 0, "block size to be non-zero");
+
+        Self {
+            generator,
+            size,
+            buffer: Mutex::new(VecDeque::with_capacity(size)),
+        }
+    }
+
+    /// Take the next buffered value, without fetching a block if the buffer is empty.
+    pub fn try_next_value(&self) -> Option<IdBytes> {
+        self.buffer().pop_front()
+    }
+
+    /// Get the number of buffered values.
+    pub fn len(&self) -> usize {
+        self.buffer().len()
+    }
+
+    /// Check if no values are buffered.
+    pub fn is_empty(&self) -> bool {
+        self.buffer().is_empty()
+    }
+
+    /// Get a reference to the inner generator.
+    pub fn generator(&self) -> &G {
+        &self.generator
+    }
+
+    fn buffer(&self) -> std::sync::MutexGuard<'_, VecDeque<IdBytes>> {
+        self.buffer.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<G: AsyncGenerator + Sync> AsyncGenerator for Buffered<G> {
+    type Error = G::Error;
+
+    async fn next_values(&self, count: usize) -> Result<Vec<IdBytes>, Self::Error> {
+        {
+            let mut buffer = self.buffer();
+
+            if !buffer.is_empty() || count == 0 {
+                let len = count.min(buffer.len());
+                return Ok(buffer.drain(..len).collect());
+            }
+        }
+
+        let mut block = self.generator.next_values(self.size.max(count)).await?;
+
+        if block.len() > count {
+            self.buffer().extend(block.drain(count..));
+        }
+
+        Ok(block)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AsyncGenerator, Buffered, GenerateError};
+    use crate::{Id, IdBytes, Type};
+    use std::convert::Infallible;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::task::{Context, Poll, Waker};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    /// A stand-in for a database sequence, allocating blocks of consecutive values below
+    /// 1000.
+    #[derive(Default)]
+    struct Sequence {
+        next: AtomicU64,
+        fetches: AtomicU64,
+    }
+
+    impl AsyncGenerator for Sequence {
+        type Error = Infallible;
+
+        async fn next_values(&self, count: usize) -> Result<Vec<IdBytes>, Self::Error> {
+            self.fetches.fetch_add(1, Ordering::Relaxed);
+            let start = self
+                .next
+                .fetch_add(count as u64, Ordering::Relaxed)
+                .min(1000);
+
+            Ok((start..(start + count as u64).min(1000))
+                .map(|value| (value as u128).to_be_bytes())
+                .collect())
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut context = Context::from_waker(Waker::noop());
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn buffered_blocks() {
+        let buffered = Buffered::new(Sequence::default(), 100);
+
+        for i in 0..250u128 {
+            let id = block_on(Id::<User>::generate_async(&buffered)).unwrap();
+            assert_eq!(id.to_u128(), i);
+        }
+
+        assert_eq!(buffered.generator().fetches.load(Ordering::Relaxed), 3);
+        assert_eq!(buffered.len(), 50);
+        assert_eq!(buffered.try_next_value(), Some(250u128.to_be_bytes()));
+
+        let values = block_on(buffered.next_values(500)).unwrap();
+        assert_eq!(values.len(), 49);
+        assert!(buffered.is_empty());
+        assert_eq!(buffered.try_next_value(), None);
+
+        let sequence = Sequence::default();
+        sequence.next.store(999, Ordering::Relaxed);
+        assert!(block_on(Id::<User>::generate_async(&sequence)).is_ok());
+        assert!(matches!(
+            block_on(Id::<User>::generate_async(&sequence)),
+            Err(GenerateError::Exhausted)
+        ));
+    }
+}
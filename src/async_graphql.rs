@@ -0,0 +1,33 @@
+use crate::{Id, Type};
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, TypeName, Value};
+use std::borrow::Cow;
+
+impl<T: Type> TypeName for Id<T> {
+    fn type_name() -> Cow<'static, str> {
+        let mut chars = T::PREFIX.chars();
+
+        match chars.next() {
+            Some(first) => format!("{}{}Id", first.to_ascii_uppercase(), chars.as_str()).into(),
+            None => "Id".into(),
+        }
+    }
+}
+
+/// A prefixed identifier, rendered as a string.
+#[Scalar(name_type)]
+impl<T: Type> ScalarType for Id<T> {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(value) => value.parse().map_err(InputValueError::custom),
+            value => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(value) if Self::test(value))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
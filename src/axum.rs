@@ -0,0 +1,85 @@
+//! Extracting identifiers in axum handlers.
+//!
+//! `Id<T>` implements `FromRequestParts`, reading the route's single path parameter:
+//!
+//! ```ignore
+//! async fn get_user(id: Id<User>) -> String {
+//!     id.to_string()
+//! }
+//!
+//! let app = Router::new().route("/users/{id}", get(get_user));
+//! ```
+//!
+//! Parse failures are rejected with an [`IdRejection`], which responds with
+//! `400 Bad Request` and a message naming the expected prefix, environment tag and format,
+//! followed by the parse error. Routes with several parameters can still use
+//! `Path<(Id<A>, Id<B>)>` through the `serde` feature.
+
+use crate::{Error, Id, Type};
+use axum::extract::rejection::PathRejection;
+use axum::extract::{FromRequestParts, Path};
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use std::fmt::{Display, Formatter};
+
+/// Rejection used when an `Id<T>` cannot be extracted from the request path.
+#[derive(Debug)]
+pub enum IdRejection {
+    /// The route does not have exactly one path parameter.
+    Path(PathRejection),
+    /// The path parameter is not a valid identifier.
+    Invalid {
+        expected: &'static str,
+        error: Error,
+    },
+}
+
+impl Display for IdRejection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Path(rejection) => write!(f, "{}", rejection.body_text()),
+            Self::Invalid { expected, error } => {
+                write!(
+                    f,
+                    "invalid `{}` identifier, expected `{}_",
+                    expected, expected
+                )?;
+
+                if let Some(environment) = crate::environment() {
+                    write!(f, "{}_", environment)?;
+                }
+
+                write!(f, "` followed by 26 base32 characters: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IdRejection {}
+
+impl IntoResponse for IdRejection {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            Self::Path(rejection) => rejection.status(),
+            Self::Invalid { .. } => StatusCode::BAD_REQUEST,
+        };
+
+        (status, self.to_string()).into_response()
+    }
+}
+
+impl<S: Send + Sync, T: Type> FromRequestParts<S> for Id<T> {
+    type Rejection = IdRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(value) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(IdRejection::Path)?;
+
+        Self::parse(&value).map_err(|error| IdRejection::Invalid {
+            expected: T::PREFIX,
+            error,
+        })
+    }
+}
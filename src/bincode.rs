@@ -0,0 +1,25 @@
+use crate::{Id, IdBytes, Type};
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+
+impl<T: Type> Encode for Id<T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_bytes().encode(encoder)
+    }
+}
+
+impl<C, T: Type> Decode<C> for Id<T> {
+    fn decode<D: Decoder<Context = C>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        IdBytes::decode(decoder).map(Self::new)
+    }
+}
+
+impl<'de, C, T: Type> BorrowDecode<'de, C> for Id<T> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = C>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        IdBytes::decode(decoder).map(Self::new)
+    }
+}
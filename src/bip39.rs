@@ -0,0 +1,78 @@
+//! Word mnemonics for identifiers, using the BIP39 English wordlist.
+//!
+//! The 16 bytes of an identifier are encoded as 12 words from the 2048 word list, with
+//! a 4-bit checksum, exactly as BIP39 encodes 128 bits of entropy. The words are chosen
+//! so the first four letters of each are unique, and the checksum catches most
+//! transcription mistakes, which suits recovery codes and identifiers read aloud or
+//! written down:
+//!
+//! ```
+//! use souvenir::{Id, Type};
+//!
+//! struct Account;
+//!
+//! impl Type for Account {
+//!     const PREFIX: &'static str = "acct";
+//! }
+//!
+//! let id: Id<Account> = Id::parse("acct_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+//! let words = id.to_words().to_string();
+//!
+//! assert_eq!(words.split(' ').count(), 12);
+//! assert_eq!(Id::<Account>::from_words(&words).unwrap(), id);
+//! ```
+//!
+//! Mnemonics don't include the prefix, so the type must be known when decoding them.
+
+use crate::{Error, Id, Type};
+use ::bip39::{Language, Mnemonic};
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Encode this identifier as a 12 word mnemonic. Format it with `Display` to get the
+    /// words separated by spaces.
+    pub fn to_words(self) -> Mnemonic {
+        Mnemonic::from_entropy_in(Language::English, self.as_bytes())
+            .expect("16 bytes to be valid entropy")
+    }
+
+    /// Attempt to decode a 12 word mnemonic into an `Id<T>`.
+    ///
+    /// Words must be lowercase and separated by whitespace. Fails with
+    /// [`Error::InvalidData`] for unknown words, the wrong number of words, or a checksum
+    /// mismatch.
+    pub fn from_words(words: &str) -> Result<Self, Error> {
+        let mnemonic = Mnemonic::parse_in_normalized(Language::English, words)
+            .map_err(|_| Error::InvalidData)?;
+
+        match mnemonic.to_entropy_array() {
+            (entropy, 16) => Self::new_checked(entropy[..16].try_into().unwrap()),
+            _ => Err(Error::InvalidData),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type};
+
+    struct Account;
+
+    impl Type for Account {
+        const PREFIX: &'static str = "acct";
+    }
+
+    #[test]
+    fn words_round_trip() {
+        // The first BIP39 test vector, for 16 zero bytes of entropy.
+        let id = Id::<Account>::new([0; 16]);
+        let words = "abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+            abandon abandon about";
+
+        assert_eq!(id.to_words().to_string(), words);
+        assert_eq!(Id::<Account>::from_words(words).unwrap(), id);
+
+        assert!(Id::<Account>::from_words(&words.replace("about", "abandon")).is_err());
+        assert!(Id::<Account>::from_words("abandon about").is_err());
+        assert!(Id::<Account>::from_words(&format!("{} abandon abandon art", words)).is_err());
+    }
+}
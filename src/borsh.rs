@@ -0,0 +1,15 @@
+use crate::{Id, IdBytes, Type};
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+impl<T: Type> BorshSerialize for Id<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(self.as_bytes())
+    }
+}
+
+impl<T: Type> BorshDeserialize for Id<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        IdBytes::deserialize_reader(reader).map(Self::new)
+    }
+}
@@ -0,0 +1,84 @@
+//! Parsing identifiers from command line arguments.
+//!
+//! `Id<T>` implements `ValueParserFactory`, so it can be used directly as an argument type:
+//!
+//! ```ignore
+//! #[derive(clap::Parser)]
+//! struct Args {
+//!     user: Id<User>,
+//! }
+//! ```
+
+use crate::{Id, Type};
+use clap::builder::{TypedValueParser, ValueParserFactory};
+use clap::error::ErrorKind;
+use clap::{Arg, Command};
+use std::ffi::OsStr;
+use std::marker::PhantomData;
+
+/// Parser for `Id<T>` arguments, reporting the expected prefix on failure.
+pub struct IdValueParser<T: Type + ?Sized> {
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Type + ?Sized> IdValueParser<T> {
+    /// Create a new `IdValueParser<T>`.
+    pub fn new() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Type + ?Sized> Default for IdValueParser<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Type + ?Sized> Clone for IdValueParser<T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Type + 'static> TypedValueParser for IdValueParser<T> {
+    type Value = Id<T>;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let value = value
+            .to_str()
+            .ok_or_else(|| clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+
+        Id::parse(value).map_err(|e| {
+            let arg = arg
+                .map(|arg| arg.to_string())
+                .unwrap_or_else(|| "...".to_owned());
+
+            clap::Error::raw(
+                ErrorKind::ValueValidation,
+                format!(
+                    "invalid value '{}' for '{}': expected a `{}_` identifier ({})\n",
+                    value,
+                    arg,
+                    T::PREFIX,
+                    e
+                ),
+            )
+            .with_cmd(cmd)
+        })
+    }
+}
+
+impl<T: Type + 'static> ValueParserFactory for Id<T> {
+    type Parser = IdValueParser<T>;
+
+    fn value_parser() -> Self::Parser {
+        IdValueParser::new()
+    }
+}
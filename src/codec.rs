@@ -0,0 +1,149 @@
+//! Compact binary encoding of identifier lists.
+//!
+//! [`delta_encode`] writes the number of identifiers followed by the difference between
+//! each value and the one before it, all as LEB128 varints. Sorted lists of time-ordered
+//! or sequential identifiers have small differences, so they take a few bytes each rather
+//! than 16:
+//!
+//! ```
+//! use souvenir::codec::{delta_decode, delta_encode};
+//! use souvenir::{Id, Type};
+//!
+//! struct User;
+//!
+//! impl Type for User {
+//!     const PREFIX: &'static str = "user";
+//! }
+//!
+//! let ids: Vec<Id<User>> = (1000..2000u128).map(Id::from).collect();
+//! let bytes = delta_encode(&ids);
+//!
+//! assert!(bytes.len() < 1010);
+//! assert_eq!(delta_decode::<User>(&bytes).unwrap(), ids);
+//! ```
+//!
+//! Differences wrap around, so unsorted lists round trip too, in their original order,
+//! but their differences are as large as the values themselves. Prefixes and environments
+//! are not encoded; both sides must agree on the type of the identifiers.
+
+use alloc::vec::Vec;
+
+use crate::{Error, Id, Type};
+
+/// The most bytes a LEB128 encoded `u128` can take.
+const MAX_VARINT_LENGTH: usize = 19;
+
+/// Encode a list of identifiers, ideally sorted, as deltas between consecutive values.
+pub fn delta_encode<T: Type + ?Sized>(ids: &[Id<T>]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(ids.len() * 2 + 2);
+    let mut last = 0u128;
+
+    write_varint(&mut out, ids.len() as u128);
+
+    for id in ids {
+        let value = id.to_u128();
+        write_varint(&mut out, value.wrapping_sub(last));
+        last = value;
+    }
+
+    out
+}
+
+/// Decode a list of identifiers written by [`delta_encode`].
+///
+/// Fails with [`Error::InvalidData`] if the input is truncated, has trailing bytes, or
+/// holds a malformed varint.
+pub fn delta_decode<T: Type + ?Sized>(mut bytes: &[u8]) -> Result<Vec<Id<T>>, Error> {
+    let len = read_varint(&mut bytes)?;
+
+    // Every delta takes at least one byte, which bounds the allocation by the input.
+    if len > bytes.len() as u128 {
+        return Err(Error::InvalidData);
+    }
+
+    let mut ids = Vec::with_capacity(len as usize);
+    let mut last = 0u128;
+
+    for _ in 0..len {
+        last = last.wrapping_add(read_varint(&mut bytes)?);
+        ids.push(Id::new(last.to_be_bytes()));
+    }
+
+    if !bytes.is_empty() {
+        return Err(Error::InvalidData);
+    }
+
+    Ok(ids)
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u128) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+
+    out.push(value as u8);
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Result<u128, Error> {
+    let mut value = 0u128;
+
+    for i in 0..MAX_VARINT_LENGTH {
+        let (&byte, rest) = bytes.split_first().ok_or(Error::InvalidData)?;
+        *bytes = rest;
+
+        let bits = (byte & 0x7f) as u128;
+
+        // The final byte only has room for the top two bits.
+        if i == MAX_VARINT_LENGTH - 1 && bits > 0b11 {
+            return Err(Error::InvalidData);
+        }
+
+        value |= bits << (7 * i);
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    Err(Error::InvalidData)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{delta_decode, delta_encode};
+    use crate::{Error, Id, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn delta_round_trip() {
+        let ids: [Id<User>; 4] = [
+            Id::from(u128::MAX),
+            Id::from(0u128),
+            Id::from(1u128 << 100),
+            Id::from((1u128 << 100) + 1),
+        ];
+
+        let bytes = delta_encode(&ids);
+        assert_eq!(delta_decode::<User>(&bytes).unwrap(), ids);
+        assert_eq!(delta_encode::<User>(&[]), [0]);
+
+        assert!(matches!(
+            delta_decode::<User>(&bytes[..bytes.len() - 1]),
+            Err(Error::InvalidData)
+        ));
+        assert!(matches!(
+            delta_decode::<User>(&[bytes.as_slice(), &[0]].concat()),
+            Err(Error::InvalidData)
+        ));
+        assert!(matches!(
+            delta_decode::<User>(&[0xff; 24]),
+            Err(Error::InvalidData)
+        ));
+    }
+}
@@ -0,0 +1,454 @@
+use crate::{Id, Type};
+use std::collections::hash_map::{self, Entry, HashMap};
+use std::collections::hash_set::{self, HashSet};
+use std::fmt::{Debug, Formatter};
+use std::hash::{BuildHasherDefault, Hasher};
+use std::iter::FromIterator;
+use std::ops::{Deref, Index};
+
+/// A fast hasher for identifiers.
+///
+/// Identifier values are already random or time-ordered, so instead of running them
+/// through SipHash this combines the two halves of the value with a multiply and rotate,
+/// then mixes the high bits down into the low bits, which the standard hash table uses to
+/// pick buckets. Values differing only in their timestamp or high bits still spread across
+/// buckets. It is not resistant to hash flooding, so attacker-chosen values should not be
+/// used as keys.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IdHasher(u64);
+
+const MULTIPLIER: u64 = 0x9e37_79b9_7f4a_7c15;
+
+impl Hasher for IdHasher {
+    fn finish(&self) -> u64 {
+        crate::hash::mix64(self.0)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut buf = [0; 8];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            self.write_u64(u64::from_le_bytes(buf));
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.0 = (self.0.rotate_left(26) ^ value).wrapping_mul(MULTIPLIER);
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        self.write_u64((value >> 64) as u64);
+        self.write_u64(value as u64);
+    }
+}
+
+/// A `BuildHasher` creating [`IdHasher`]s.
+pub type IdBuildHasher = BuildHasherDefault<IdHasher>;
+
+/// A hash map keyed by `Id<T>`.
+///
+/// Keys are hashed with [`IdHasher`], and the key type names the entity it identifies, so
+/// maps keyed by different types can't be mixed up. The full `HashMap` API is available
+/// through `Deref`.
+///
+/// ```
+/// use souvenir::{Id, IdMap, Type};
+///
+/// struct User;
+///
+/// impl Type for User {
+///     const PREFIX: &'static str = "user";
+/// }
+///
+/// let id: Id<User> = Id::random();
+///
+/// let mut names = IdMap::new();
+/// names.insert(id, "ferris");
+/// assert_eq!(names[&id], "ferris");
+/// ```
+pub struct IdMap<T: Type + ?Sized, V> {
+    inner: HashMap<Id<T>, V, IdBuildHasher>,
+}
+
+impl<T: Type + ?Sized, V> IdMap<T, V> {
+    /// Create an empty `IdMap`.
+    pub fn new() -> Self {
+        Self {
+            inner: HashMap::default(),
+        }
+    }
+
+    /// Create an empty `IdMap` with space for at least `capacity` entries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: HashMap::with_capacity_and_hasher(capacity, IdBuildHasher::default()),
+        }
+    }
+
+    /// Insert a value, returning the previous value for the identifier.
+    pub fn insert(&mut self, id: Id<T>, value: V) -> Option<V> {
+        self.inner.insert(id, value)
+    }
+
+    /// Get the value for an identifier.
+    pub fn get(&self, id: &Id<T>) -> Option<&V> {
+        self.inner.get(id)
+    }
+
+    /// Get the value for an identifier mutably.
+    pub fn get_mut(&mut self, id: &Id<T>) -> Option<&mut V> {
+        self.inner.get_mut(id)
+    }
+
+    /// Remove the value for an identifier, returning it.
+    pub fn remove(&mut self, id: &Id<T>) -> Option<V> {
+        self.inner.remove(id)
+    }
+
+    /// Test whether the map holds a value for an identifier.
+    pub fn contains_key(&self, id: &Id<T>) -> bool {
+        self.inner.contains_key(id)
+    }
+
+    /// Get the entry for an identifier for in-place manipulation.
+    pub fn entry(&mut self, id: Id<T>) -> Entry<'_, Id<T>, V> {
+        self.inner.entry(id)
+    }
+
+    /// Iterate over the values mutably.
+    pub fn values_mut(&mut self) -> hash_map::ValuesMut<'_, Id<T>, V> {
+        self.inner.values_mut()
+    }
+
+    /// Iterate over the entries, with mutable values.
+    pub fn iter_mut(&mut self) -> hash_map::IterMut<'_, Id<T>, V> {
+        self.inner.iter_mut()
+    }
+
+    /// Keep only the entries matching the predicate.
+    pub fn retain(&mut self, f: impl FnMut(&Id<T>, &mut V) -> bool) {
+        self.inner.retain(f)
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
+    /// Get the underlying `HashMap`.
+    pub fn into_inner(self) -> HashMap<Id<T>, V, IdBuildHasher> {
+        self.inner
+    }
+}
+
+impl<T: Type + ?Sized, V> Deref for IdMap<T, V> {
+    type Target = HashMap<Id<T>, V, IdBuildHasher>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: Type + ?Sized, V> Default for IdMap<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Type + ?Sized, V: Clone> Clone for IdMap<T, V> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Type + ?Sized, V: PartialEq> PartialEq for IdMap<T, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Type + ?Sized, V: Eq> Eq for IdMap<T, V> {}
+
+impl<T: Type + ?Sized, V: Debug> Debug for IdMap<T, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.inner.iter()).finish()
+    }
+}
+
+impl<T: Type + ?Sized, V> Index<&Id<T>> for IdMap<T, V> {
+    type Output = V;
+
+    fn index(&self, id: &Id<T>) -> &V {
+        &self.inner[id]
+    }
+}
+
+impl<T: Type + ?Sized, V> FromIterator<(Id<T>, V)> for IdMap<T, V> {
+    fn from_iter<I: IntoIterator<Item = (Id<T>, V)>>(iter: I) -> Self {
+        Self {
+            inner: HashMap::from_iter(iter),
+        }
+    }
+}
+
+impl<T: Type + ?Sized, V> Extend<(Id<T>, V)> for IdMap<T, V> {
+    fn extend<I: IntoIterator<Item = (Id<T>, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<T: Type + ?Sized, V> IntoIterator for IdMap<T, V> {
+    type Item = (Id<T>, V);
+    type IntoIter = hash_map::IntoIter<Id<T>, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T: Type + ?Sized, V> IntoIterator for &'a IdMap<T, V> {
+    type Item = (&'a Id<T>, &'a V);
+    type IntoIter = hash_map::Iter<'a, Id<T>, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<'a, T: Type + ?Sized, V> IntoIterator for &'a mut IdMap<T, V> {
+    type Item = (&'a Id<T>, &'a mut V);
+    type IntoIter = hash_map::IterMut<'a, Id<T>, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter_mut()
+    }
+}
+
+/// A hash set of `Id<T>`.
+///
+/// Like [`IdMap`], identifiers are hashed with [`IdHasher`], which keeps membership checks
+/// over large sets cheap. The full `HashSet` API is available through `Deref`.
+///
+/// ```
+/// use souvenir::{Id, IdSet, Type};
+///
+/// struct User;
+///
+/// impl Type for User {
+///     const PREFIX: &'static str = "user";
+/// }
+///
+/// let a: Id<User> = Id::random();
+/// let b: Id<User> = Id::random();
+///
+/// let visible: IdSet<User> = [a, b].into_iter().collect();
+/// let blocked: IdSet<User> = [b].into_iter().collect();
+///
+/// let allowed: IdSet<User> = visible.difference(&blocked).collect();
+/// assert!(allowed.contains(&a));
+/// assert!(!allowed.contains(&b));
+/// ```
+pub struct IdSet<T: Type + ?Sized> {
+    inner: HashSet<Id<T>, IdBuildHasher>,
+}
+
+impl<T: Type + ?Sized> IdSet<T> {
+    /// Create an empty `IdSet`.
+    pub fn new() -> Self {
+        Self {
+            inner: HashSet::default(),
+        }
+    }
+
+    /// Create an empty `IdSet` with space for at least `capacity` identifiers.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: HashSet::with_capacity_and_hasher(capacity, IdBuildHasher::default()),
+        }
+    }
+
+    /// Add an identifier, returning whether it was newly inserted.
+    pub fn insert(&mut self, id: Id<T>) -> bool {
+        self.inner.insert(id)
+    }
+
+    /// Remove an identifier, returning whether it was present.
+    pub fn remove(&mut self, id: &Id<T>) -> bool {
+        self.inner.remove(id)
+    }
+
+    /// Test whether the set holds an identifier.
+    pub fn contains(&self, id: &Id<T>) -> bool {
+        self.inner.contains(id)
+    }
+
+    /// Iterate over the identifiers in either set.
+    pub fn union<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = Id<T>> + 'a {
+        self.inner.union(&other.inner).copied()
+    }
+
+    /// Iterate over the identifiers in both sets.
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = Id<T>> + 'a {
+        self.inner.intersection(&other.inner).copied()
+    }
+
+    /// Iterate over the identifiers in this set but not in `other`.
+    pub fn difference<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = Id<T>> + 'a {
+        self.inner.difference(&other.inner).copied()
+    }
+
+    /// Iterate over the identifiers in exactly one of the sets.
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = Id<T>> + 'a {
+        self.inner.symmetric_difference(&other.inner).copied()
+    }
+
+    /// Test whether every identifier in this set is also in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.inner.is_subset(&other.inner)
+    }
+
+    /// Test whether every identifier in `other` is also in this set.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        self.inner.is_superset(&other.inner)
+    }
+
+    /// Test whether the sets have no identifiers in common.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.inner.is_disjoint(&other.inner)
+    }
+
+    /// Keep only the identifiers matching the predicate.
+    pub fn retain(&mut self, f: impl FnMut(&Id<T>) -> bool) {
+        self.inner.retain(f)
+    }
+
+    /// Remove all identifiers.
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
+    /// Get the underlying `HashSet`.
+    pub fn into_inner(self) -> HashSet<Id<T>, IdBuildHasher> {
+        self.inner
+    }
+}
+
+impl<T: Type + ?Sized> Deref for IdSet<T> {
+    type Target = HashSet<Id<T>, IdBuildHasher>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: Type + ?Sized> Default for IdSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Type + ?Sized> Clone for IdSet<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq for IdSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Type + ?Sized> Eq for IdSet<T> {}
+
+impl<T: Type + ?Sized> Debug for IdSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.inner.iter()).finish()
+    }
+}
+
+impl<T: Type + ?Sized> FromIterator<Id<T>> for IdSet<T> {
+    fn from_iter<I: IntoIterator<Item = Id<T>>>(iter: I) -> Self {
+        Self {
+            inner: HashSet::from_iter(iter),
+        }
+    }
+}
+
+impl<T: Type + ?Sized> Extend<Id<T>> for IdSet<T> {
+    fn extend<I: IntoIterator<Item = Id<T>>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<T: Type + ?Sized> IntoIterator for IdSet<T> {
+    type Item = Id<T>;
+    type IntoIter = hash_set::IntoIter<Id<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T: Type + ?Sized> IntoIterator for &'a IdSet<T> {
+    type Item = &'a Id<T>;
+    type IntoIter = hash_set::Iter<'a, Id<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, IdBuildHasher, IdMap, IdSet, Type};
+    use std::collections::HashSet;
+    use std::hash::BuildHasher;
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn map_sequential_ids() {
+        let mut map: IdMap<User, u128> = (0..10_000u128).map(|i| (Id::from(i), i)).collect();
+
+        assert_eq!(map.len(), 10_000);
+        assert_eq!(map[&Id::from(1234u128)], 1234);
+        assert_eq!(map.remove(&Id::from(0u128)), Some(0));
+        assert!(!map.contains_key(&Id::from(0u128)));
+    }
+
+    #[test]
+    fn hash_high_bits() {
+        let buckets = |ids: &mut dyn Iterator<Item = u128>| {
+            ids.map(|i| IdBuildHasher::default().hash_one(Id::<User>::from(i)) & 0x3ff)
+                .collect::<HashSet<_>>()
+                .len()
+        };
+
+        // 4096 keys into 1024 buckets should leave few of them empty.
+        assert!(buckets(&mut (0..4096u128).map(|i| i << 112)) > 900);
+        assert!(buckets(&mut (0..4096u128).map(|i| i << 80)) > 900);
+        assert!(buckets(&mut (0..4096u128).map(|i| i << 64 | i)) > 900);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: IdSet<User> = (0..10u128).map(Id::from).collect();
+        let b: IdSet<User> = (5..15u128).map(Id::from).collect();
+
+        assert_eq!(a.union(&b).count(), 15);
+        assert_eq!(a.intersection(&b).count(), 5);
+        assert_eq!(a.difference(&b).count(), 5);
+        assert_eq!(a.symmetric_difference(&b).count(), 10);
+        assert!(a.contains(&Id::from(3u128)));
+        assert!(!a.is_disjoint(&b));
+    }
+}
@@ -0,0 +1,92 @@
+use core::fmt::{Display, Formatter};
+
+use crate::hash::mix64;
+use crate::{Id, Type};
+
+/// Saturation of derived colors, in thousandths.
+const SATURATION: u32 = 650;
+/// Lightness of derived colors, in thousandths.
+const LIGHTNESS: u32 = 500;
+
+/// A color derived from an identifier with [`Id::to_color`].
+///
+/// Colors share the same saturation and lightness and only differ in hue, so a set of
+/// avatars looks consistent. Formatting a color gives its `#rrggbb` hex code.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Color {
+    hue: u16,
+}
+
+impl Color {
+    /// Get the hue of this color, in degrees from 0 to 359.
+    pub fn hue(self) -> u16 {
+        self.hue
+    }
+
+    /// Get the red, green and blue components of this color.
+    pub fn rgb(self) -> [u8; 3] {
+        let hue = self.hue as u32;
+
+        // Convert from HSL with integer arithmetic, so results are identical everywhere.
+        let chroma = (1000 - (2 * LIGHTNESS).abs_diff(1000)) * SATURATION / 1000;
+        let x = chroma * (60 - (hue % 120).abs_diff(60)) / 60;
+        let m = LIGHTNESS - chroma / 2;
+
+        let (r, g, b) = match hue / 60 {
+            0 => (chroma, x, 0),
+            1 => (x, chroma, 0),
+            2 => (0, chroma, x),
+            3 => (0, x, chroma),
+            4 => (x, 0, chroma),
+            _ => (chroma, 0, x),
+        };
+
+        [r, g, b].map(|c| (((c + m) * 255 + 500) / 1000) as u8)
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let [r, g, b] = self.rgb();
+        write!(f, "#{:02x}{:02x}{:02x}", r, g, b)
+    }
+}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Get a color for this identifier, such as for the background of an avatar.
+    ///
+    /// The hue is derived from a hash of the 16 bytes of the identifier and is stable
+    /// across releases, so an entity always gets the same color, and similar identifiers,
+    /// such as consecutive time-ordered ones, get unrelated colors.
+    pub fn to_color(self) -> Color {
+        let value = self.to_u128();
+        let hash = mix64(value as u64 ^ mix64((value >> 64) as u64));
+
+        Color {
+            hue: (hash % 360) as u16,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Color, Id, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn color_values() {
+        assert_eq!(Color { hue: 0 }.rgb(), [210, 45, 45]);
+        assert_eq!(Color { hue: 120 }.rgb(), [45, 210, 45]);
+        assert_eq!(Color { hue: 240 }.to_string(), "#2d2dd2");
+
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+        assert_eq!(id.to_color(), id.to_color());
+        assert!(id.to_color().hue() < 360);
+        assert_ne!(id.to_color(), Id::<User>::from(id.to_u128() + 1).to_color());
+    }
+}
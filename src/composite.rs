@@ -0,0 +1,181 @@
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use crate::encoding::{format_base32, parse_base32};
+use crate::{Error, Id, Segment, Type};
+
+/// A key made of two identifiers, such as a follower and the user they follow.
+///
+/// Composite identifiers are ordered by their first identifier, then their second, and
+/// are formatted with both prefixes followed by both values, like
+/// `user.org_02v58c5a3fy30k560qrtg4rb2k7zzzzzzzzzzzzzzzzzzzzzzzzz`. Environment tags are
+/// not included.
+pub struct CompositeId<A: Type + ?Sized, B: Type + ?Sized> {
+    first: Id<A>,
+    second: Id<B>,
+}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> CompositeId<A, B> {
+    /// Create a new composite identifier from its two parts.
+    pub fn new(first: Id<A>, second: Id<B>) -> Self {
+        Self { first, second }
+    }
+
+    /// Get the first identifier.
+    pub fn first(self) -> Id<A> {
+        self.first
+    }
+
+    /// Get the second identifier.
+    pub fn second(self) -> Id<B> {
+        self.second
+    }
+
+    /// Split this composite identifier into its two parts.
+    pub fn into_parts(self) -> (Id<A>, Id<B>) {
+        (self.first, self.second)
+    }
+
+    /// Test to see if the provided string is a valid `CompositeId<A, B>`.
+    pub fn test(value: &str) -> bool {
+        Self::parse(value).is_ok()
+    }
+
+    /// Attempt to parse the provided string into a `CompositeId<A, B>`.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let (prefix, value) = value.split_once('_').ok_or(Error::InvalidData)?;
+        let (first, second) = prefix.split_once('.').ok_or(Error::InvalidData)?;
+
+        for (name, expected, actual) in [(A::NAME, A::PREFIX, first), (B::NAME, B::PREFIX, second)]
+        {
+            if expected != actual {
+                return Err(Error::PrefixMismatch {
+                    name,
+                    expected,
+                    actual: Segment::new(actual),
+                });
+            }
+        }
+
+        if value.len() != 52 || !value.is_char_boundary(26) {
+            return Err(Error::InvalidData);
+        }
+
+        let (first, second) = value.split_at(26);
+
+        Ok(Self::new(
+            Id::new_checked(parse_base32(first)?)?,
+            Id::new_checked(parse_base32(second)?)?,
+        ))
+    }
+}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> Copy for CompositeId<A, B> {}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> Clone for CompositeId<A, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> PartialEq for CompositeId<A, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.first == other.first && self.second == other.second
+    }
+}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> Eq for CompositeId<A, B> {}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> PartialOrd for CompositeId<A, B> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> Ord for CompositeId<A, B> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.first, self.second).cmp(&(other.first, other.second))
+    }
+}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> Hash for CompositeId<A, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.first.hash(state);
+        self.second.hash(state);
+    }
+}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> Debug for CompositeId<A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> Display for CompositeId<A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}_", A::PREFIX, B::PREFIX)?;
+
+        for value in [self.first.to_bytes(), self.second.to_bytes()] {
+            f.write_str(
+                core::str::from_utf8(&format_base32(value))
+                    .expect("only ascii bytes should be in the buffer"),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> FromStr for CompositeId<A, B> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> From<(Id<A>, Id<B>)> for CompositeId<A, B> {
+    fn from((first, second): (Id<A>, Id<B>)) -> Self {
+        Self::new(first, second)
+    }
+}
+
+impl<A: Type + ?Sized, B: Type + ?Sized> From<CompositeId<A, B>> for (Id<A>, Id<B>) {
+    fn from(value: CompositeId<A, B>) -> Self {
+        value.into_parts()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CompositeId, Id, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    struct Org;
+
+    impl Type for Org {
+        const PREFIX: &'static str = "org";
+    }
+
+    #[test]
+    fn composite_round_trip() {
+        let user: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+        let org: Id<Org> = Id::parse("org_7zzzzzzzzzzzzzzzzzzzzzzzzz").unwrap();
+        let id = CompositeId::new(user, org);
+
+        assert_eq!(
+            id.to_string(),
+            "user.org_02v58c5a3fy30k560qrtg4rb2k7zzzzzzzzzzzzzzzzzzzzzzzzz"
+        );
+        assert_eq!(CompositeId::parse(&id.to_string()).unwrap(), id);
+        assert_eq!(id.into_parts(), (user, org));
+        assert!(CompositeId::<Org, User>::parse(&id.to_string()).is_err());
+        assert!(CompositeId::new(user, Id::new([0; 16])) < id);
+    }
+}
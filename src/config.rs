@@ -0,0 +1,425 @@
+use std::ffi::OsString;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::encoding::{parse_base32_any_case, parse_base32_lenient};
+use crate::time::TIMESTAMP_BITS;
+use crate::Error;
+#[cfg(feature = "rand")]
+use crate::{Generator, IdBytes, MultiRegion, Random, Timestamped};
+#[cfg(feature = "rand")]
+use rand::RngCore;
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+static DEFAULT: Config = Config::new();
+
+/// Start configuring the process-wide defaults, which are applied with [`Config::apply`].
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// souvenir::configure()
+///     .epoch(UNIX_EPOCH + Duration::from_millis(1_700_000_000_000))
+///     .environment("test")
+///     .lenient(true)
+///     .apply()
+///     .unwrap();
+///
+/// assert_eq!(souvenir::environment(), Some("test"));
+/// ```
+pub fn configure() -> Config {
+    Config::new()
+}
+
+/// Start configuring the process-wide defaults from environment variables, so that
+/// deployments can tune generation without code changes.
+///
+/// | Variable             | Setting                                                    |
+/// |----------------------|------------------------------------------------------------|
+/// | `SOUVENIR_EPOCH`     | [`Config::epoch`], in milliseconds since the Unix epoch     |
+/// | `SOUVENIR_ENV_TAG`   | [`Config::environment`]                                     |
+/// | `SOUVENIR_WORKER_ID` | [`Policy::MultiRegion`] with this worker, from 0 to 65535  |
+/// | `SOUVENIR_REGION_ID` | the region for `SOUVENIR_WORKER_ID`, from 0 to 255, or 0   |
+///
+/// Unset or empty variables keep the defaults, and other settings can be chained before
+/// the configuration is applied:
+///
+/// ```no_run
+/// souvenir::configure_from_env()?.lenient(true).apply().unwrap();
+/// # Ok::<(), souvenir::ConfigError>(())
+/// ```
+pub fn configure_from_env() -> Result<Config, ConfigError> {
+    from_vars(|variable| std::env::var_os(variable))
+}
+
+fn from_vars(var: impl Fn(&str) -> Option<OsString>) -> Result<Config, ConfigError> {
+    let var = |variable: &'static str| match var(variable) {
+        Some(value) if value.is_empty() => Ok(None),
+        Some(value) => value.into_string().map(Some).map_err(|value| ConfigError {
+            variable,
+            value: value.to_string_lossy().into_owned(),
+        }),
+        None => Ok(None),
+    };
+
+    let mut config = configure();
+
+    if let Some(value) = var("SOUVENIR_EPOCH")? {
+        let millis = parse_var("SOUVENIR_EPOCH", value)?;
+        config = config.epoch(UNIX_EPOCH + Duration::from_millis(millis));
+    }
+
+    if let Some(value) = var("SOUVENIR_ENV_TAG")? {
+        if !is_environment(&value) {
+            return Err(ConfigError {
+                variable: "SOUVENIR_ENV_TAG",
+                value,
+            });
+        }
+
+        // Tags are `'static`, and configuration is only read once per process.
+        config = config.environment(Box::leak(value.into_boxed_str()));
+    }
+
+    #[cfg(feature = "rand")]
+    if let Some(value) = var("SOUVENIR_WORKER_ID")? {
+        let worker = parse_var("SOUVENIR_WORKER_ID", value)?;
+        let region = match var("SOUVENIR_REGION_ID")? {
+            Some(value) => parse_var("SOUVENIR_REGION_ID", value)?,
+            None => 0,
+        };
+
+        config = config.policy(Policy::MultiRegion { region, worker });
+    }
+
+    Ok(config)
+}
+
+fn parse_var<V: FromStr>(variable: &'static str, value: String) -> Result<V, ConfigError> {
+    value.parse().map_err(|_| ConfigError { variable, value })
+}
+
+/// Check that an environment tag is made of lowercase ASCII letters and digits.
+fn is_environment(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+}
+
+/// An environment variable with an invalid value, from [`configure_from_env`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigError {
+    variable: &'static str,
+    value: String,
+}
+
+impl ConfigError {
+    /// Get the name of the variable.
+    pub fn variable(&self) -> &'static str {
+        self.variable
+    }
+
+    /// Get the invalid value of the variable.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid value `{}` for {}", self.value, self.variable)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Get the applied configuration, or the defaults if none was applied.
+pub(crate) fn current() -> &'static Config {
+    CONFIG.get().unwrap_or(&DEFAULT)
+}
+
+/// Process-wide defaults for generating and parsing identifiers, built with
+/// [`configure`].
+///
+/// Configuration is applied once, ideally at the start of `main`. Identifiers generated
+/// or parsed before then use the defaults, which are documented with each setting.
+#[derive(Debug)]
+pub struct Config {
+    epoch: SystemTime,
+    environment: Option<&'static str>,
+    lenient: bool,
+    #[cfg(feature = "rand")]
+    rng: Option<fn(&mut [u8])>,
+    #[cfg(feature = "rand")]
+    policy: Policy,
+    #[cfg(feature = "rand")]
+    multi_region: MultiRegion,
+}
+
+impl Config {
+    const fn new() -> Self {
+        Self {
+            epoch: UNIX_EPOCH,
+            environment: None,
+            lenient: false,
+            #[cfg(feature = "rand")]
+            rng: None,
+            #[cfg(feature = "rand")]
+            policy: Policy::Random,
+            #[cfg(feature = "rand")]
+            multi_region: MultiRegion::new(0, 0),
+        }
+    }
+
+    /// Set the epoch of the timestamps in time-ordered identifiers, by default the Unix
+    /// epoch.
+    ///
+    /// The epoch is used by the time-ordered generators, and when reading timestamps back
+    /// with `Id::created_at`, `Id::time_bucket` or `Id::min_for_timestamp`. Conversions
+    /// to and from ULIDs, UUIDv7 and other imported formats always use the Unix epoch, so
+    /// only set an epoch for identifiers which are not exchanged in those formats.
+    pub fn epoch(mut self, epoch: SystemTime) -> Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /// Set the environment tag, by default none. See [`set_environment`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tag is empty or contains anything other than lowercase ASCII letters
+    /// and digits.
+    ///
+    /// [`set_environment`]: crate::set_environment
+    pub fn environment(mut self, name: &'static str) -> Self {
+        assert!(
+            is_environment(name),
+            "environment tag must be lowercase alphanumeric, found `{}`",
+            name
+        );
+
+        self.environment = Some(name);
+        self
+    }
+
+    /// Set whether parsing is lenient, by default `false`.
+    ///
+    /// Lenient parsing accepts identifiers surrounded by whitespace and values in mixed
+    /// case, and reads the letters `i` and `l` as `1` and `o` as `0`, as Crockford's
+    /// base32 allows, which helps with identifiers typed in by hand. Prefixes and
+    /// environment tags are still matched exactly.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Set the source of randomness for random values, by default the thread-local
+    /// random number generator from `rand`.
+    ///
+    /// The source must fill the provided buffer entirely. It is used by `Id::random`,
+    /// `Generic::random` and the [`Random`], [`Timestamped`] and [`MultiRegion`]
+    /// generators, for example to draw from the operating system directly, or from a
+    /// seeded generator in tests.
+    #[cfg(feature = "rand")]
+    pub fn rng(mut self, source: fn(&mut [u8])) -> Self {
+        self.rng = Some(source);
+        self
+    }
+
+    /// Set the generator used by [`Configured`], by default [`Policy::Random`].
+    #[cfg(feature = "rand")]
+    pub fn policy(mut self, policy: Policy) -> Self {
+        if let Policy::MultiRegion { region, worker } = policy {
+            self.multi_region = MultiRegion::new(region, worker);
+        }
+
+        self.policy = policy;
+        self
+    }
+
+    /// Apply this configuration to the process.
+    ///
+    /// Configuration can only be applied once, including by [`set_environment`]; later
+    /// calls return the rejected configuration.
+    ///
+    /// [`set_environment`]: crate::set_environment
+    pub fn apply(self) -> Result<(), Self> {
+        CONFIG.set(self)
+    }
+
+    pub(crate) fn environment_tag(&self) -> Option<&'static str> {
+        self.environment
+    }
+
+    /// Get the milliseconds between the epoch and a time, clamped to the timestamp range.
+    pub(crate) fn to_millis(&self, time: SystemTime) -> u64 {
+        let millis = time
+            .duration_since(self.epoch)
+            .unwrap_or(Duration::ZERO)
+            .as_millis();
+
+        millis.min((1 << TIMESTAMP_BITS) - 1) as u64
+    }
+
+    /// Get the time a number of milliseconds after the epoch.
+    pub(crate) fn to_time(&self, millis: u64) -> SystemTime {
+        self.epoch + Duration::from_millis(millis)
+    }
+
+    /// Remove the surrounding whitespace from a string to parse, if lenient.
+    pub(crate) fn trim<'a>(&self, value: &'a str) -> &'a str {
+        match self.lenient {
+            true => value.trim(),
+            false => value,
+        }
+    }
+
+    /// Parse the base32 value of an identifier.
+    pub(crate) fn parse_value(&self, value: &str) -> Result<[u8; 16], Error> {
+        match self.lenient {
+            true => parse_base32_lenient(value),
+            false => parse_base32_any_case(value),
+        }
+    }
+}
+
+/// The generator used by [`Configured`].
+#[cfg(feature = "rand")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Policy {
+    /// Random values, as from [`Random`].
+    #[default]
+    Random,
+    /// Time-ordered values, as from [`Timestamped`].
+    Timestamped,
+    /// Time-ordered values from a single process-wide [`MultiRegion`] generator for the
+    /// region and worker.
+    MultiRegion { region: u8, worker: u16 },
+}
+
+/// A generator following the process-wide [`Policy`] set with [`configure`], so the
+/// choice of generator can be made once rather than at every call site.
+#[cfg(feature = "rand")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Configured;
+
+#[cfg(feature = "rand")]
+impl Generator for Configured {
+    fn next_value(&self) -> IdBytes {
+        let config = current();
+
+        match config.policy {
+            Policy::Random => Random.next_value(),
+            Policy::Timestamped => Timestamped.next_value(),
+            Policy::MultiRegion { .. } => config.multi_region.next_value(),
+        }
+    }
+}
+
+/// A random number generator drawing from the configured source of randomness.
+#[cfg(feature = "rand")]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct ConfiguredRng;
+
+#[cfg(feature = "rand")]
+impl RngCore for ConfiguredRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match current().rng {
+            Some(source) => source(dest),
+            None => rand::thread_rng().fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{configure, from_vars};
+    use std::ffi::OsString;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn configured_settings() {
+        let epoch = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        let config = configure().epoch(epoch).lenient(true);
+
+        assert_eq!(config.to_millis(epoch + Duration::from_millis(5)), 5);
+        assert_eq!(config.to_millis(UNIX_EPOCH), 0);
+        assert_eq!(config.to_time(5), epoch + Duration::from_millis(5));
+
+        assert_eq!(config.trim(" user_01 \n"), "user_01");
+        assert_eq!(
+            config.parse_value("O2V58C5A3FY30K56OQRTG4RB2k").unwrap(),
+            config.parse_value("02v58c5a3fy30k560qrtg4rb2k").unwrap()
+        );
+
+        let config = configure();
+        assert_eq!(config.trim(" user_01"), " user_01");
+        config
+            .parse_value("O2V58C5A3FY30K56OQRTG4RB2k")
+            .expect_err("should have failed");
+    }
+
+    #[test]
+    fn configured_from_vars() {
+        let vars = |vars: &'static [(&str, &str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| OsString::from(value))
+            }
+        };
+
+        let config = from_vars(vars(&[
+            ("SOUVENIR_EPOCH", "1700000000000"),
+            ("SOUVENIR_ENV_TAG", "staging"),
+            ("SOUVENIR_WORKER_ID", "12"),
+            ("SOUVENIR_REGION_ID", ""),
+        ]))
+        .unwrap();
+
+        assert_eq!(config.to_millis(UNIX_EPOCH), 0);
+        assert_eq!(
+            config.to_time(0),
+            UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+        );
+        assert_eq!(config.environment_tag(), Some("staging"));
+
+        #[cfg(feature = "rand")]
+        assert_eq!(
+            config.policy,
+            super::Policy::MultiRegion {
+                region: 0,
+                worker: 12
+            }
+        );
+
+        let error = from_vars(vars(&[("SOUVENIR_WORKER_ID", "70000")])).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid value `70000` for SOUVENIR_WORKER_ID"
+        );
+
+        let error = from_vars(vars(&[("SOUVENIR_ENV_TAG", "Live")])).unwrap_err();
+        assert_eq!(error.variable(), "SOUVENIR_ENV_TAG");
+    }
+}
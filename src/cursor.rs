@@ -0,0 +1,165 @@
+use crate::encoding::{decode_base32, encode_base32};
+use crate::{Error, Id, IdBytes, Type};
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+/// An opaque pagination cursor pointing at an `Id<T>`.
+///
+/// A cursor holds the identifier of the last item on a page, along with optional extra
+/// bytes such as a sort key or a direction flag. It is formatted as a single base32 token
+/// which clients should treat as opaque.
+///
+/// ```
+/// use souvenir::{Cursor, Id, Type};
+///
+/// struct User;
+///
+/// impl Type for User {
+///     const PREFIX: &'static str = "user";
+/// }
+///
+/// let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+/// let cursor = Cursor::with_extra(id, [1]);
+/// let token = cursor.to_string();
+///
+/// let parsed: Cursor<User> = Cursor::parse(&token).unwrap();
+/// assert_eq!(parsed.id(), id);
+/// assert_eq!(parsed.extra(), &[1]);
+/// ```
+pub struct Cursor<T: Type + ?Sized> {
+    id: Id<T>,
+    extra: Vec<u8>,
+}
+
+impl<T: Type + ?Sized> Cursor<T> {
+    /// Create a new cursor pointing at the provided identifier.
+    pub fn new(id: Id<T>) -> Self {
+        Self::with_extra(id, Vec::new())
+    }
+
+    /// Create a new cursor pointing at the provided identifier, carrying extra bytes.
+    pub fn with_extra(id: Id<T>, extra: impl Into<Vec<u8>>) -> Self {
+        Self {
+            id,
+            extra: extra.into(),
+        }
+    }
+
+    /// Get the identifier this cursor points at.
+    pub fn id(&self) -> Id<T> {
+        self.id
+    }
+
+    /// Get the extra bytes carried by this cursor.
+    pub fn extra(&self) -> &[u8] {
+        &self.extra
+    }
+
+    /// Split this cursor into its identifier and extra bytes.
+    pub fn into_parts(self) -> (Id<T>, Vec<u8>) {
+        (self.id, self.extra)
+    }
+
+    /// Test to see if the provided string is a valid `Cursor<T>`.
+    pub fn test(value: &str) -> bool {
+        Self::parse(value).is_ok()
+    }
+
+    /// Attempt to parse the provided token into a `Cursor<T>`.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let mut data = decode_base32(value)?;
+
+        if data.len() < size_of::<IdBytes>() {
+            return Err(Error::InvalidData);
+        }
+
+        let extra = data.split_off(size_of::<IdBytes>());
+        Ok(Self::with_extra(Id::try_from(&data[..])?, extra))
+    }
+
+    /// Format this cursor as an opaque token.
+    pub fn format(&self) -> String {
+        let mut data = Vec::with_capacity(size_of::<IdBytes>() + self.extra.len());
+        data.extend_from_slice(self.id.as_bytes());
+        data.extend_from_slice(&self.extra);
+
+        encode_base32(&data)
+    }
+}
+
+impl<T: Type + ?Sized> Clone for Cursor<T> {
+    fn clone(&self) -> Self {
+        Self::with_extra(self.id, self.extra.clone())
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq for Cursor<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.extra == other.extra
+    }
+}
+
+impl<T: Type + ?Sized> Eq for Cursor<T> {}
+
+impl<T: Type + ?Sized> Hash for Cursor<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.extra.hash(state);
+    }
+}
+
+impl<T: Type + ?Sized> From<Id<T>> for Cursor<T> {
+    fn from(value: Id<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Type + ?Sized> Debug for Cursor<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl<T: Type + ?Sized> Display for Cursor<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.format())
+    }
+}
+
+impl<T: Type + ?Sized> FromStr for Cursor<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Cursor, Id, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn cursor_round_trip() {
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+
+        for extra in [&b""[..], b"\x01", b"created_at:1700000000"] {
+            let cursor = Cursor::with_extra(id, extra);
+            let parsed: Cursor<User> = cursor.to_string().parse().unwrap();
+            assert_eq!(parsed, cursor);
+        }
+    }
+
+    #[test]
+    fn cursor_too_short() {
+        Cursor::<User>::parse("cr").expect_err("should have failed");
+        Cursor::<User>::parse("0u").expect_err("should have failed");
+    }
+}
@@ -0,0 +1,18 @@
+use crate::encoding::format_base32;
+use crate::{Id, Type};
+use defmt::{Format, Formatter};
+
+impl<T: Type + ?Sized> Format for Id<T> {
+    fn format(&self, f: Formatter<'_>) {
+        let value = format_base32(*self.as_bytes());
+        let value = core::str::from_utf8(&value).expect("only ascii bytes should be in the buffer");
+
+        #[cfg(feature = "std")]
+        if let Some(environment) = crate::environment() {
+            defmt::write!(f, "{=str}_{=str}_{=str}", T::PREFIX, environment, value);
+            return;
+        }
+
+        defmt::write!(f, "{=str}_{=str}", T::PREFIX, value);
+    }
+}
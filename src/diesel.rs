@@ -1,8 +1,11 @@
 #[cfg(feature = "diesel-postgres")]
 mod pg {
-    use crate::{Id, Type};
+    use crate::{Generic, Id, Type};
     use diesel::pg::{Pg, PgValue};
-    use diesel::{deserialize, serialize, sql_types::Uuid};
+    use diesel::{
+        deserialize, serialize,
+        sql_types::{Text, Uuid},
+    };
     use std::io::Write;
 
     impl<T: Type> serialize::ToSql<Uuid, Pg> for Id<T> {
@@ -18,13 +21,30 @@ mod pg {
             value.as_bytes().try_into().map_err(Into::into)
         }
     }
+
+    impl serialize::ToSql<Text, Pg> for Generic {
+        fn to_sql<'b>(&'b self, out: &mut serialize::Output<'b, '_, Pg>) -> serialize::Result {
+            write!(out, "{}", self)
+                .map(|_| serialize::IsNull::No)
+                .map_err(Into::into)
+        }
+    }
+
+    impl deserialize::FromSql<Text, Pg> for Generic {
+        fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+            Self::parse(std::str::from_utf8(value.as_bytes())?).map_err(Into::into)
+        }
+    }
 }
 
 #[cfg(feature = "diesel-mysql")]
 mod mysql {
-    use crate::{Id, Type};
+    use crate::{Generic, Id, Type};
     use diesel::mysql::{Mysql, MysqlValue};
-    use diesel::{deserialize, serialize, sql_types::Binary};
+    use diesel::{
+        deserialize, serialize,
+        sql_types::{Binary, Text},
+    };
     use std::io::Write;
 
     impl<T: Type> serialize::ToSql<Binary, Mysql> for Id<T> {
@@ -40,11 +60,25 @@ mod mysql {
             value.as_bytes().try_into().map_err(Into::into)
         }
     }
+
+    impl serialize::ToSql<Text, Mysql> for Generic {
+        fn to_sql<'b>(&'b self, out: &mut serialize::Output<'b, '_, Mysql>) -> serialize::Result {
+            write!(out, "{}", self)
+                .map(|_| serialize::IsNull::No)
+                .map_err(Into::into)
+        }
+    }
+
+    impl deserialize::FromSql<Text, Mysql> for Generic {
+        fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+            Self::parse(std::str::from_utf8(value.as_bytes())?).map_err(Into::into)
+        }
+    }
 }
 
 #[cfg(feature = "diesel-sqlite")]
 mod sqlite {
-    use crate::{Id, Type};
+    use crate::{Generic, Id, Type};
     use diesel::sqlite::{Sqlite, SqliteValue};
     use diesel::{deserialize, serialize, sql_types::Text};
 
@@ -62,4 +96,19 @@ mod sqlite {
                 .map_err(Into::into)
         }
     }
+
+    impl serialize::ToSql<Text, Sqlite> for Generic {
+        fn to_sql<'b>(&'b self, out: &mut serialize::Output<'b, '_, Sqlite>) -> serialize::Result {
+            out.set_value(self.to_string());
+            Ok(serialize::IsNull::No)
+        }
+    }
+
+    impl deserialize::FromSql<Text, Sqlite> for Generic {
+        fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+            <String as deserialize::FromSql<Text, Sqlite>>::from_sql(value)?
+                .parse()
+                .map_err(Into::into)
+        }
+    }
 }
@@ -1,6 +1,10 @@
 use crate::Error;
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+use crate::Type;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
 
-const CROCKFORD: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+pub(crate) const CROCKFORD: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
 const CROCKFORD_INV: &[u8; 256] = &{
     let mut output = [255; 256];
 
@@ -13,6 +17,21 @@ const CROCKFORD_INV: &[u8; 256] = &{
     output
 };
 
+#[cfg(feature = "alloc")]
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+#[cfg(feature = "alloc")]
+const BASE64_INV: &[u8; 256] = &{
+    let mut output = [255; 256];
+
+    let mut i = 0;
+    while i < 64 {
+        output[BASE64[i as usize] as usize] = i;
+        i += 1;
+    }
+
+    output
+};
+
 pub fn parse_base32(id: &str) -> Result<[u8; 16], Error> {
     let mut id: [u8; 26] = id.as_bytes().try_into().map_err(|_| Error::InvalidData)?;
     let mut max = 0;
@@ -35,7 +54,45 @@ pub fn parse_base32(id: &str) -> Result<[u8; 16], Error> {
     Ok(out.to_be_bytes())
 }
 
-pub fn stringify_base32(id: [u8; 16]) -> Result<String, Error> {
+/// Parse a base32 value written entirely in lowercase, or entirely in uppercase.
+pub fn parse_base32_any_case(id: &str) -> Result<[u8; 16], Error> {
+    if id.bytes().any(|b| b.is_ascii_lowercase()) {
+        return parse_base32(id);
+    }
+
+    let mut buf: [u8; 26] = id.as_bytes().try_into().map_err(|_| Error::InvalidData)?;
+    buf.make_ascii_lowercase();
+
+    parse_base32(core::str::from_utf8(&buf).map_err(|_| Error::InvalidData)?)
+}
+
+/// Parse a base32 value in any mix of cases, reading the letters `i` and `l` as `1` and
+/// `o` as `0`.
+#[cfg(feature = "std")]
+pub fn parse_base32_lenient(id: &str) -> Result<[u8; 16], Error> {
+    let mut buf: [u8; 26] = id.as_bytes().try_into().map_err(|_| Error::InvalidData)?;
+
+    for b in &mut buf {
+        *b = match b.to_ascii_lowercase() {
+            b'i' | b'l' => b'1',
+            b'o' => b'0',
+            b => b,
+        };
+    }
+
+    parse_base32(core::str::from_utf8(&buf).map_err(|_| Error::InvalidData)?)
+}
+
+/// Parse the base32 value of an identifier, leniently if [configured](crate::Config::lenient).
+pub fn parse_value(id: &str) -> Result<[u8; 16], Error> {
+    #[cfg(feature = "std")]
+    return crate::config::current().parse_value(id);
+
+    #[cfg(not(feature = "std"))]
+    parse_base32_any_case(id)
+}
+
+pub fn format_base32(id: [u8; 16]) -> [u8; 26] {
     let mut buf = [0; 26];
     let mut data = u128::from_be_bytes(id);
 
@@ -45,12 +102,213 @@ pub fn stringify_base32(id: [u8; 16]) -> Result<String, Error> {
         data >>= 5;
     }
 
-    Ok(String::from_utf8(buf.to_vec()).expect("only ascii bytes should be in the buffer"))
+    buf
+}
+
+#[cfg(feature = "alloc")]
+pub fn stringify_base32(id: [u8; 16]) -> Result<String, Error> {
+    Ok(String::from_utf8(format_base32(id).to_vec())
+        .expect("only ascii bytes should be in the buffer"))
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_base32(data: &[u8]) -> String {
+    let mut out = String::with_capacity((data.len() * 8).div_ceil(5));
+    let mut buffer = 0u16;
+    let mut bits = 0;
+
+    for b in data {
+        buffer = (buffer << 8) | *b as u16;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            out.push(CROCKFORD[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+
+    if bits > 0 {
+        out.push(CROCKFORD[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+
+    out
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_base32(data: &str) -> Result<Vec<u8>, Error> {
+    let mut out = Vec::with_capacity(data.len() * 5 / 8);
+    let mut buffer = 0u16;
+    let mut bits = 0;
+
+    for b in data.bytes() {
+        let value = CROCKFORD_INV[b as usize];
+        if value == 255 {
+            return Err(Error::InvalidData);
+        }
+
+        buffer = (buffer << 5) | value as u16;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+
+    // leftover bits must be zero padding shorter than a full character
+    if bits >= 5 || buffer & ((1 << bits) - 1) != 0 {
+        return Err(Error::InvalidData);
+    }
+
+    Ok(out)
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_base64(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let mut n = 0u32;
+        for (i, b) in chunk.iter().enumerate() {
+            n |= (*b as u32) << (16 - 8 * i);
+        }
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_base64(data: &str) -> Result<Vec<u8>, Error> {
+    let data = data.as_bytes();
+    if !data.len().is_multiple_of(4) {
+        return Err(Error::InvalidData);
+    }
+
+    let mut out = Vec::with_capacity(data.len() / 4 * 3);
+    let chunks = data.len() / 4;
+
+    for (index, chunk) in data.chunks(4).enumerate() {
+        let padding = chunk.iter().rev().take_while(|b| **b == b'=').count();
+        if padding > 2 || (padding > 0 && index != chunks - 1) {
+            return Err(Error::InvalidData);
+        }
+
+        let mut n = 0u32;
+        for (i, b) in chunk[..4 - padding].iter().enumerate() {
+            let value = BASE64_INV[*b as usize];
+            if value == 255 {
+                return Err(Error::InvalidData);
+            }
+
+            n |= (value as u32) << (18 - 6 * i);
+        }
+
+        // Bits past the last decoded byte must be zero, so each input has one encoding.
+        if n & ((1 << (8 * padding)) - 1) != 0 {
+            return Err(Error::InvalidData);
+        }
+
+        out.extend_from_slice(&n.to_be_bytes()[1..4 - padding]);
+    }
+
+    Ok(out)
+}
+
+/// Test whether a byte is in the RFC 3986 unreserved set, which is also filename-safe.
+#[cfg(feature = "alloc")]
+fn is_unreserved(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~')
+}
+
+#[cfg(feature = "alloc")]
+pub fn percent_encode(data: &str) -> String {
+    let mut out = String::with_capacity(data.len());
+
+    for b in data.bytes() {
+        if is_unreserved(b) {
+            out.push(b as char);
+        } else {
+            out.push('%');
+            out.push(char::from(b"0123456789ABCDEF"[(b >> 4) as usize]));
+            out.push(char::from(b"0123456789ABCDEF"[(b & 0xf) as usize]));
+        }
+    }
+
+    out
+}
+
+#[cfg(feature = "alloc")]
+pub fn percent_decode(data: &str) -> Result<String, Error> {
+    let mut out = Vec::with_capacity(data.len());
+    let mut bytes = data.bytes();
+
+    while let Some(b) = bytes.next() {
+        if b != b'%' {
+            out.push(b);
+            continue;
+        }
+
+        let mut value = 0;
+        for _ in 0..2 {
+            let digit = bytes
+                .next()
+                .and_then(|b| (b as char).to_digit(16))
+                .ok_or(Error::InvalidData)?;
+            value = value << 4 | digit as u8;
+        }
+
+        out.push(value);
+    }
+
+    String::from_utf8(out).map_err(|_| Error::InvalidData)
+}
+
+/// Value used for example identifiers in generated schemas.
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+pub const EXAMPLE: [u8; 16] = [
+    0x02, 0xd9, 0x50, 0xc2, 0xa8, 0x6f, 0xf0, 0xc1, 0x32, 0x98, 0x17, 0xc6, 0xa0, 0x4c, 0x2c, 0x53,
+];
+
+/// Build a regular expression matching identifiers with the given prefix, in either case,
+/// with or without an environment tag.
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+pub fn pattern(prefix: &str) -> String {
+    let mut pattern = String::from("^");
+
+    for c in prefix.chars() {
+        if !c.is_ascii_alphanumeric() {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+
+    pattern.push_str("_(?:[a-z0-9]+_)?[0-7][0-9a-hjkmnp-tv-zA-HJKMNP-TV-Z]{25}$");
+    pattern
+}
+
+/// Get the schema description of identifiers of a type, its own description if it has one.
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+pub fn description<T: Type + ?Sized>() -> String {
+    match T::DESCRIPTION {
+        "" => format!("A {} identifier.", T::NAME),
+        description => String::from(description),
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::encoding::{parse_base32, stringify_base32};
+    use crate::encoding::{
+        decode_base32, decode_base64, encode_base32, encode_base64, parse_base32, percent_decode,
+        percent_encode, stringify_base32,
+    };
     use rand::random;
 
     #[test]
@@ -96,8 +354,84 @@ mod test {
         for _ in 0..100000 {
             let value: [u8; 16] = random();
             let result = stringify_base32(value).unwrap();
-            let parsed = parse_base32(&*result).unwrap();
+            let parsed = parse_base32(&result).unwrap();
             assert_eq!(value, parsed);
         }
     }
+
+    #[test]
+    fn base32_round_trip() {
+        for len in 0..40 {
+            let data: Vec<u8> = (0..len).map(|_| random()).collect();
+            assert_eq!(decode_base32(&encode_base32(&data)).unwrap(), data);
+        }
+
+        assert_eq!(encode_base32(b"f"), "cr");
+        decode_base32("cs").expect_err("should have failed");
+        decode_base32("crc").expect_err("should have failed");
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        for (data, encoded) in [
+            (&b""[..], ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (b"fooba", "Zm9vYmE="),
+            (b"foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(encode_base64(data), encoded);
+            assert_eq!(decode_base64(encoded).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn base64_invalid() {
+        decode_base64("Zm9").expect_err("should have failed");
+        decode_base64("Zg==Zm9v").expect_err("should have failed");
+        decode_base64("Zm9*").expect_err("should have failed");
+        decode_base64("Zh==").expect_err("should have failed");
+        decode_base64("Zm9=").expect_err("should have failed");
+    }
+
+    #[test]
+    fn percent_round_trip() {
+        assert_eq!(percent_encode("user_02v5"), "user_02v5");
+        assert_eq!(percent_encode("a/b c"), "a%2Fb%20c");
+        assert_eq!(percent_decode("a%2fb%20c").unwrap(), "a/b c");
+        percent_decode("a%2").expect_err("should have failed");
+        percent_decode("a%zz").expect_err("should have failed");
+    }
+
+    #[test]
+    #[cfg(any(feature = "schemars", feature = "utoipa"))]
+    fn pattern_escapes_prefix() {
+        use crate::encoding::pattern;
+
+        assert_eq!(
+            pattern("user"),
+            "^user_(?:[a-z0-9]+_)?[0-7][0-9a-hjkmnp-tv-zA-HJKMNP-TV-Z]{25}$"
+        );
+        assert_eq!(
+            pattern("a.b"),
+            "^a\\.b_(?:[a-z0-9]+_)?[0-7][0-9a-hjkmnp-tv-zA-HJKMNP-TV-Z]{25}$"
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "schemars", feature = "utoipa"))]
+    fn pattern_environment_tag() {
+        use crate::encoding::pattern;
+        use regex_lite::Regex;
+
+        let pattern = Regex::new(&pattern("user")).unwrap();
+
+        assert!(pattern.is_match("user_02v58c5a3fy30k560qrtg4rb2k"));
+        assert!(pattern.is_match("user_test_02v58c5a3fy30k560qrtg4rb2k"));
+        assert!(pattern.is_match("user_eu1_02v58c5a3fy30k560qrtg4rb2k"));
+        assert!(!pattern.is_match("user_test_02v58c5a3fy30k560qrtg4rb2"));
+        assert!(!pattern.is_match("user__02v58c5a3fy30k560qrtg4rb2k"));
+    }
 }
@@ -0,0 +1,70 @@
+use crate::{Error, Segment};
+
+/// Set the process-wide environment tag, such as `test` or `live`.
+///
+/// Once set, identifiers render with the tag after their prefix (`user_test_…`) and
+/// parsing rejects identifiers whose tag is missing or different, so ids minted in one
+/// environment can't silently be used in another. When no tag is set, parsing rejects
+/// identifiers that carry one.
+///
+/// This is a shorthand for `configure().environment(name).apply()`. The tag can only be
+/// set once, and not after [`configure`](crate::configure) was applied; later calls
+/// return the rejected tag.
+///
+/// # Panics
+///
+/// Panics if the tag is empty or contains anything other than lowercase ASCII letters
+/// and digits.
+pub fn set_environment(name: &'static str) -> Result<(), &'static str> {
+    crate::configure()
+        .environment(name)
+        .apply()
+        .map_err(|_| name)
+}
+
+/// Get the process-wide environment tag, if one was set.
+pub fn environment() -> Option<&'static str> {
+    crate::config::current().environment_tag()
+}
+
+/// Strip the environment tag from the part of an identifier following its prefix.
+pub(crate) fn strip(value: &str) -> Result<&str, Error> {
+    strip_with(value, environment())
+}
+
+fn strip_with<'a>(value: &'a str, expected: Option<&'static str>) -> Result<&'a str, Error> {
+    let (actual, value) = match value.split_once('_') {
+        Some((actual, value)) => (Some(actual), value),
+        None => (None, value),
+    };
+
+    if actual != expected {
+        return Err(Error::EnvironmentMismatch {
+            expected,
+            actual: actual.map(Segment::new),
+        });
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::strip_with;
+
+    #[test]
+    fn environment_enforced() {
+        let value = "02v58c5a3fy30k560qrtg4rb2k";
+
+        assert_eq!(strip_with(value, None).unwrap(), value);
+        assert_eq!(
+            strip_with("test_02v58c5a3fy30k560qrtg4rb2k", Some("test")).unwrap(),
+            value
+        );
+
+        strip_with(value, Some("test")).expect_err("should have failed");
+        strip_with("test_02v58c5a3fy30k560qrtg4rb2k", None).expect_err("should have failed");
+        strip_with("live_02v58c5a3fy30k560qrtg4rb2k", Some("test"))
+            .expect_err("should have failed");
+    }
+}
@@ -1,18 +1,119 @@
-use std::fmt::{Display, Formatter};
+use core::fmt::{Debug, Display, Formatter};
+
+/// The most bytes of a [`Segment`] kept in an error.
+const SEGMENT_CAPACITY: usize = 63;
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     InvalidData,
+    InvalidPrefix,
     PrefixMismatch {
+        name: &'static str,
         expected: &'static str,
-        actual: String,
+        actual: Segment,
+    },
+    EnvironmentMismatch {
+        expected: Option<&'static str>,
+        actual: Option<Segment>,
     },
+    InvalidSignature,
+    Expired,
+}
+
+/// Part of an identifier found while parsing, such as its prefix or environment tag.
+///
+/// Segments are stored inline, so errors have the same shape with or without `alloc`,
+/// and are truncated to 63 bytes, which fits every valid `Generic` prefix.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Segment {
+    bytes: [u8; SEGMENT_CAPACITY],
+    len: u8,
+}
+
+impl Segment {
+    pub(crate) fn new(value: &str) -> Self {
+        let mut len = value.len().min(SEGMENT_CAPACITY);
+
+        while !value.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        let mut bytes = [0; SEGMENT_CAPACITY];
+        bytes[..len].copy_from_slice(&value.as_bytes()[..len]);
+
+        Self {
+            bytes,
+            len: len as u8,
+        }
+    }
+
+    /// Get this segment as a string.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len as usize])
+            .expect("segment to be cut at a character boundary")
+    }
+}
+
+impl AsRef<str> for Segment {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Debug for Segment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for Segment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl Display for Error {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidData => write!(f, "invalid identifier data"),
+            Self::InvalidPrefix => write!(f, "invalid identifier prefix"),
+            Self::PrefixMismatch {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "expected {} identifier `{}_…`, found prefix `{}`",
+                name, expected, actual
+            ),
+            Self::EnvironmentMismatch { expected, actual } => match (expected, actual) {
+                (Some(expected), Some(actual)) => {
+                    write!(f, "expected environment `{}`, found `{}`", expected, actual)
+                }
+                (Some(expected), None) => write!(f, "expected environment `{}`", expected),
+                (None, actual) => write!(
+                    f,
+                    "unexpected environment `{}`",
+                    actual.as_ref().map_or("", Segment::as_str)
+                ),
+            },
+            Self::InvalidSignature => write!(f, "invalid identifier signature"),
+            Self::Expired => write!(f, "identifier has expired"),
+        }
     }
 }
 
-impl std::error::Error for Error {}
+impl core::error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::Segment;
+
+    #[test]
+    fn segment_truncation() {
+        assert_eq!(Segment::new("user").as_str(), "user");
+        assert_eq!(Segment::new(&"a".repeat(80)).as_str(), "a".repeat(63));
+        assert_eq!(Segment::new(&"é".repeat(40)).as_str(), "é".repeat(31));
+    }
+}
@@ -0,0 +1,221 @@
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use crate::{Error, Id, Type, VersionedId};
+
+/// An HTTP entity tag built from a [`VersionedId`].
+///
+/// Strong tags are formatted as the quoted versioned identifier, like
+/// `"user_02v58c5a3fy30k560qrtg4rb2k.v3"`, and weak tags add the `W/` prefix. Use
+/// [`ETag::matches_if_match`] and [`ETag::matches_if_none_match`] to evaluate the
+/// conditional request headers against the current version of a resource:
+///
+/// ```
+/// use souvenir::{Id, Type};
+///
+/// struct User;
+///
+/// impl Type for User {
+///     const PREFIX: &'static str = "user";
+/// }
+///
+/// let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+/// let current = id.etag(3);
+///
+/// assert!(current.matches_if_match(r#""user_02v58c5a3fy30k560qrtg4rb2k.v3""#));
+/// assert!(!current.matches_if_match(r#""user_02v58c5a3fy30k560qrtg4rb2k.v2""#));
+/// ```
+pub struct ETag<T: Type + ?Sized> {
+    versioned: VersionedId<T>,
+    weak: bool,
+}
+
+impl<T: Type + ?Sized> ETag<T> {
+    /// Create a strong entity tag for a versioned identifier.
+    pub fn strong(versioned: VersionedId<T>) -> Self {
+        Self {
+            versioned,
+            weak: false,
+        }
+    }
+
+    /// Create a weak entity tag for a versioned identifier.
+    pub fn weak(versioned: VersionedId<T>) -> Self {
+        Self {
+            versioned,
+            weak: true,
+        }
+    }
+
+    /// Get the versioned identifier of this tag.
+    pub fn versioned(self) -> VersionedId<T> {
+        self.versioned
+    }
+
+    /// Get the identifier of this tag.
+    pub fn id(self) -> Id<T> {
+        self.versioned.id()
+    }
+
+    /// Get the version of this tag.
+    pub fn version(self) -> u64 {
+        self.versioned.version()
+    }
+
+    /// Check if this is a weak tag.
+    pub fn is_weak(self) -> bool {
+        self.weak
+    }
+
+    /// Test to see if the provided string is a valid `ETag<T>`.
+    pub fn test(value: &str) -> bool {
+        Self::parse(value).is_ok()
+    }
+
+    /// Attempt to parse the provided string into an `ETag<T>`.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let (weak, value) = match value.strip_prefix("W/") {
+            Some(value) => (true, value),
+            None => (false, value),
+        };
+
+        let value = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .ok_or(Error::InvalidData)?;
+
+        Ok(Self {
+            versioned: VersionedId::parse(value)?,
+            weak,
+        })
+    }
+
+    /// Check if an `If-Match` header value matches this tag, the current tag of a resource.
+    ///
+    /// This uses the strong comparison from RFC 9110: the header matches if it is `*` or
+    /// lists this tag, with neither being weak. Entries which aren't tags for `T` are
+    /// ignored.
+    pub fn matches_if_match(self, header: &str) -> bool {
+        self.matches(header, |other| !self.weak && !other.weak)
+    }
+
+    /// Check if an `If-None-Match` header value matches this tag, the current tag of a
+    /// resource.
+    ///
+    /// This uses the weak comparison from RFC 9110: the header matches if it is `*` or
+    /// lists this tag, ignoring whether either is weak.
+    pub fn matches_if_none_match(self, header: &str) -> bool {
+        self.matches(header, |_| true)
+    }
+
+    fn matches(self, header: &str, compatible: impl Fn(Self) -> bool) -> bool {
+        if header.trim() == "*" {
+            return true;
+        }
+
+        header
+            .split(',')
+            .filter_map(|entry| Self::parse(entry.trim()).ok())
+            .any(|other| other.versioned == self.versioned && compatible(other))
+    }
+}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Get the strong entity tag for the provided version of the record with this
+    /// identifier.
+    pub fn etag(self, version: u64) -> ETag<T> {
+        ETag::strong(VersionedId::new(self, version))
+    }
+
+    /// Get the weak entity tag for the provided version of the record with this
+    /// identifier.
+    pub fn weak_etag(self, version: u64) -> ETag<T> {
+        ETag::weak(VersionedId::new(self, version))
+    }
+
+    /// Attempt to parse the provided strong or weak entity tag.
+    pub fn parse_etag(value: &str) -> Result<ETag<T>, Error> {
+        ETag::parse(value)
+    }
+}
+
+impl<T: Type + ?Sized> Copy for ETag<T> {}
+
+impl<T: Type + ?Sized> Clone for ETag<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq for ETag<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.versioned == other.versioned && self.weak == other.weak
+    }
+}
+
+impl<T: Type + ?Sized> Eq for ETag<T> {}
+
+impl<T: Type + ?Sized> Hash for ETag<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.versioned.hash(state);
+        self.weak.hash(state);
+    }
+}
+
+impl<T: Type + ?Sized> Debug for ETag<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl<T: Type + ?Sized> Display for ETag<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.weak {
+            f.write_str("W/")?;
+        }
+
+        write!(f, "\"{}\"", self.versioned)
+    }
+}
+
+impl<T: Type + ?Sized> FromStr for ETag<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ETag, Id, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn etag_conditions() {
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+        let strong = id.etag(3);
+        let weak = id.weak_etag(3);
+
+        assert_eq!(strong.to_string(), "\"user_02v58c5a3fy30k560qrtg4rb2k.v3\"");
+        assert_eq!(weak.to_string(), "W/\"user_02v58c5a3fy30k560qrtg4rb2k.v3\"");
+        assert_eq!(Id::parse_etag(&weak.to_string()).unwrap(), weak);
+        assert!(ETag::<User>::parse("user_02v58c5a3fy30k560qrtg4rb2k.v3").is_err());
+
+        let header = format!("\"other\", {}", strong);
+        assert!(strong.matches_if_match(&header));
+        assert!(strong.matches_if_match("*"));
+        assert!(!weak.matches_if_match(&header));
+        assert!(!strong.matches_if_match(&weak.to_string()));
+        assert!(!id.etag(4).matches_if_match(&header));
+
+        assert!(weak.matches_if_none_match(&header));
+        assert!(!id.etag(4).matches_if_none_match(&header));
+    }
+}
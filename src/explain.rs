@@ -0,0 +1,234 @@
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::time::{to_time, TIMESTAMP_BITS};
+use crate::{Generic, Id, Type};
+
+/// The layout of an identifier value, naming the generator which produced it.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Layout {
+    /// The generator is not known. A timestamp is reported if the top 48 bits hold a
+    /// plausible time, between the year 2000 and now.
+    #[default]
+    Unknown,
+    /// Random values, with no structure.
+    Random,
+    /// Values from the `Timestamped` generator.
+    Timestamped,
+    /// Values from the [`Sequential`](crate::Sequential) generator.
+    Sequential,
+    /// Values from the `MultiRegion` generator.
+    MultiRegion,
+}
+
+/// A structured description of an identifier, from [`Id::describe`].
+///
+/// Formatting a description gives one aligned `field  value` line per known field, which
+/// is what [`Id::explain`] returns.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Description {
+    prefix: String,
+    value: u128,
+    layout: Layout,
+}
+
+impl Description {
+    fn new(prefix: &str, value: u128, layout: Layout) -> Self {
+        Self {
+            prefix: String::from(prefix),
+            value,
+            layout,
+        }
+    }
+
+    /// Get the prefix of the identifier.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Get the value of the identifier.
+    pub fn value(&self) -> u128 {
+        self.value
+    }
+
+    /// Get the value of the identifier as 32 lowercase hexadecimal digits.
+    pub fn hex(&self) -> String {
+        format!("{:032x}", self.value)
+    }
+
+    /// Get the layout the identifier was described with.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Get the creation time of the identifier, if its layout has a timestamp.
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        let time = to_time((self.value >> (128 - TIMESTAMP_BITS)) as u64);
+
+        match self.layout {
+            Layout::Timestamped | Layout::MultiRegion => Some(time),
+            Layout::Unknown
+                if time >= UNIX_EPOCH + Duration::from_millis(946_684_800_000)
+                    && time <= SystemTime::now() =>
+            {
+                Some(time)
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the region code of the identifier, if its layout has one.
+    pub fn region(&self) -> Option<u8> {
+        match self.layout {
+            Layout::MultiRegion => Some((self.value >> 72) as u8),
+            _ => None,
+        }
+    }
+
+    /// Get the worker, or shard, number of the identifier, if its layout has one.
+    pub fn worker(&self) -> Option<u16> {
+        match self.layout {
+            Layout::MultiRegion => Some((self.value >> 56) as u16),
+            _ => None,
+        }
+    }
+
+    /// Get the sequence number of the identifier, if its layout has one.
+    pub fn sequence(&self) -> Option<u128> {
+        match self.layout {
+            Layout::Sequential => Some(self.value),
+            Layout::MultiRegion => Some((self.value >> 32) & 0xff_ffff),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Description {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "prefix     {}", self.prefix)?;
+        writeln!(f, "hex        {}", self.hex())?;
+        write!(f, "u128       {}", self.value)?;
+
+        if self.layout != Layout::Unknown {
+            write!(f, "\nlayout     {:?}", self.layout)?;
+        }
+
+        if let Some(time) = self.timestamp() {
+            write!(f, "\ntimestamp  {}", rfc3339(time))?;
+        }
+
+        if let Some(region) = self.region() {
+            write!(f, "\nregion     {}", region)?;
+        }
+
+        if let Some(worker) = self.worker() {
+            write!(f, "\nworker     {}", worker)?;
+        }
+
+        if let Some(sequence) = self.sequence() {
+            write!(f, "\nsequence   {}", sequence)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Format a time as an RFC 3339 UTC timestamp with millisecond precision.
+fn rfc3339(time: SystemTime) -> String {
+    let millis = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_millis() as u64);
+    let (days, rem) = ((millis / 86_400_000) as i64, millis % 86_400_000);
+
+    // Convert days since the Unix epoch to a civil date, from Howard Hinnant's algorithms.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        rem / 3_600_000,
+        rem / 60_000 % 60,
+        rem / 1000 % 60,
+        rem % 1000
+    )
+}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Describe this identifier, decoding its fields with the provided layout.
+    pub fn describe(self, layout: Layout) -> Description {
+        Description::new(T::PREFIX, self.to_u128(), layout)
+    }
+
+    /// Explain this identifier in a human-readable form, with one field per line.
+    ///
+    /// The generator is not known, so only the timestamp is decoded, when plausible; use
+    /// [`Id::describe`] with a [`Layout`] to decode other fields.
+    pub fn explain(self) -> String {
+        self.describe(Layout::Unknown).to_string()
+    }
+}
+
+impl Generic {
+    /// Describe this identifier, decoding its fields with the provided layout.
+    pub fn describe(&self, layout: Layout) -> Description {
+        Description::new(self.prefix(), self.to_u128(), layout)
+    }
+
+    /// Explain this identifier in a human-readable form, with one field per line.
+    ///
+    /// See [`Id::explain`] for the fields which are decoded.
+    pub fn explain(&self) -> String {
+        self.describe(Layout::Unknown).to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Layout, Type};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn describe_layouts() {
+        let value = 1_700_000_000_000u128 << 80 | 3 << 72 | 513 << 56 | 7 << 32 | 0xdead;
+        let id = Id::<User>::from(value);
+
+        let description = id.describe(Layout::MultiRegion);
+        assert_eq!(description.prefix(), "user");
+        assert_eq!(
+            description.timestamp(),
+            Some(UNIX_EPOCH + Duration::from_millis(1_700_000_000_000))
+        );
+        assert_eq!(description.region(), Some(3));
+        assert_eq!(description.worker(), Some(513));
+        assert_eq!(description.sequence(), Some(7));
+
+        assert_eq!(
+            id.explain(),
+            "prefix     user\n\
+             hex        018bcfe568000302010000070000dead\n\
+             u128       2055173893344883801065493580457500333\n\
+             timestamp  2023-11-14T22:13:20.000Z"
+        );
+
+        let id = Id::<User>::from(42u128);
+        assert_eq!(id.describe(Layout::Sequential).sequence(), Some(42));
+        assert_eq!(id.describe(Layout::Unknown).timestamp(), None);
+        assert_eq!(id.describe(Layout::Random).sequence(), None);
+    }
+}
@@ -0,0 +1,8 @@
+use crate::{Id, IdBytes, Type};
+use fake::{Dummy, Faker, RngExt};
+
+impl<T: Type> Dummy<Faker> for Id<T> {
+    fn dummy_with_rng<R: RngExt + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+        Self::new(IdBytes::dummy_with_rng(config, rng))
+    }
+}
@@ -0,0 +1,147 @@
+//! C ABI helpers for parsing and formatting identifiers.
+//!
+//! Identifiers cross the boundary as their 16 big-endian bytes (`uint8_t[16]`), which is
+//! also the layout of `Id<T>` itself. Prefixes and identifier strings are NUL-terminated.
+//!
+//! ```c
+//! int souvenir_parse(const char *prefix, const char *input, uint8_t out[16]);
+//! intptr_t souvenir_format(const char *prefix, const uint8_t value[16], char *buf, size_t len);
+//! ```
+
+use crate::{Error, Generic, IdBytes};
+use std::ffi::{c_char, c_int, CStr};
+
+/// The call succeeded.
+pub const SOUVENIR_OK: c_int = 0;
+
+/// The identifier was malformed.
+pub const SOUVENIR_INVALID_DATA: c_int = -1;
+
+/// The identifier had a different prefix than expected.
+pub const SOUVENIR_PREFIX_MISMATCH: c_int = -2;
+
+/// An argument was null or not valid UTF-8.
+pub const SOUVENIR_INVALID_ARGUMENT: c_int = -3;
+
+unsafe fn to_str<'a>(value: *const c_char) -> Option<&'a str> {
+    if value.is_null() {
+        return None;
+    }
+
+    CStr::from_ptr(value).to_str().ok()
+}
+
+/// Parse `input` as an identifier with the provided prefix, writing its 16 bytes to
+/// `out` and returning `SOUVENIR_OK` on success. `out` is left untouched on failure.
+///
+/// # Safety
+///
+/// `prefix` and `input` must be null or point to NUL-terminated strings, and `out` must
+/// be null or valid for writing 16 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn souvenir_parse(
+    prefix: *const c_char,
+    input: *const c_char,
+    out: *mut IdBytes,
+) -> c_int {
+    let (Some(prefix), Some(input)) = (to_str(prefix), to_str(input)) else {
+        return SOUVENIR_INVALID_ARGUMENT;
+    };
+
+    if out.is_null() {
+        return SOUVENIR_INVALID_ARGUMENT;
+    }
+
+    match Generic::parse(input) {
+        Ok(id) if id.prefix() == prefix => {
+            out.write(*id.as_bytes());
+            SOUVENIR_OK
+        }
+        Ok(_) | Err(Error::PrefixMismatch { .. }) => SOUVENIR_PREFIX_MISMATCH,
+        Err(_) => SOUVENIR_INVALID_DATA,
+    }
+}
+
+/// Format the identifier with the provided prefix and 16 bytes into `buf`, followed by a
+/// NUL terminator.
+///
+/// Returns the length of the formatted identifier, excluding the terminator. If `buf` is
+/// smaller than that length plus one, nothing is written, so a call with a null `buf`
+/// and a `len` of zero can be used to size the buffer. Returns
+/// `SOUVENIR_INVALID_ARGUMENT` if `prefix` or `value` is invalid.
+///
+/// # Safety
+///
+/// `prefix` must be null or point to a NUL-terminated string, `value` must be null or
+/// valid for reading 16 bytes, and `buf` must be valid for writing `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn souvenir_format(
+    prefix: *const c_char,
+    value: *const IdBytes,
+    buf: *mut c_char,
+    len: usize,
+) -> isize {
+    let Some(prefix) = to_str(prefix) else {
+        return SOUVENIR_INVALID_ARGUMENT as isize;
+    };
+
+    if value.is_null() {
+        return SOUVENIR_INVALID_ARGUMENT as isize;
+    }
+
+    let Ok(id) = Generic::new(prefix, value.read()) else {
+        return SOUVENIR_INVALID_ARGUMENT as isize;
+    };
+
+    let formatted = id.to_string();
+
+    if !buf.is_null() && formatted.len() < len {
+        buf.copy_from_nonoverlapping(formatted.as_ptr().cast(), formatted.len());
+        buf.add(formatted.len()).write(0);
+    }
+
+    formatted.len() as isize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ffi_round_trip() {
+        let mut value = [0; 16];
+        let mut buf = [0 as c_char; 64];
+
+        unsafe {
+            assert_eq!(
+                souvenir_parse(
+                    c"user".as_ptr(),
+                    c"user_02v58c5a3fy30k560qrtg4rb2k".as_ptr(),
+                    &mut value
+                ),
+                SOUVENIR_OK
+            );
+            assert_eq!(
+                souvenir_parse(
+                    c"org".as_ptr(),
+                    c"user_02v58c5a3fy30k560qrtg4rb2k".as_ptr(),
+                    &mut value
+                ),
+                SOUVENIR_PREFIX_MISMATCH
+            );
+
+            assert_eq!(
+                souvenir_format(c"user".as_ptr(), &value, std::ptr::null_mut(), 0),
+                31
+            );
+            assert_eq!(
+                souvenir_format(c"user".as_ptr(), &value, buf.as_mut_ptr(), buf.len()),
+                31
+            );
+            assert_eq!(
+                CStr::from_ptr(buf.as_ptr()).to_str().unwrap(),
+                "user_02v58c5a3fy30k560qrtg4rb2k"
+            );
+        }
+    }
+}
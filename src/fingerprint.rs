@@ -0,0 +1,74 @@
+use core::fmt::{Display, Formatter};
+
+use crate::encoding::CROCKFORD;
+use crate::{Id, Type};
+
+/// A short code derived from an identifier with [`Id::fingerprint`].
+///
+/// Formatting a fingerprint gives four lowercase base32 characters, or uppercase with
+/// `{:#}`, from the same alphabet as identifiers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Fingerprint {
+    code: [u8; 4],
+}
+
+impl Fingerprint {
+    /// Get this fingerprint as a string.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.code).expect("only ascii bytes should be in the code")
+    }
+}
+
+impl Display for Fingerprint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match f.alternate() {
+            true => {
+                let code = self.code.map(|b| b.to_ascii_uppercase());
+                f.write_str(
+                    core::str::from_utf8(&code).expect("only ascii bytes should be in the code"),
+                )
+            }
+            false => f.write_str(self.as_str()),
+        }
+    }
+}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Get a four character fingerprint of this identifier, to display next to a
+    /// truncated identifier so that people can check two references are to the same
+    /// entity.
+    ///
+    /// The fingerprint is the top 20 bits of [`Id::stable_hash`] in base32, so it is
+    /// stable across releases, and identifiers which only differ in a few characters, such
+    /// as consecutive time-ordered ones, get unrelated fingerprints. With about a million
+    /// fingerprints, two different identifiers rarely share one, but they are not unique
+    /// and must not be used to look identifiers up.
+    pub fn fingerprint(self) -> Fingerprint {
+        let hash = self.stable_hash() >> 44;
+
+        Fingerprint {
+            code: [15, 10, 5, 0].map(|shift| CROCKFORD[(hash >> shift) as usize & 0x1f]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn fingerprint_codes() {
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+        let next: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2m").unwrap();
+
+        assert_eq!(id.fingerprint().as_str(), "zmxv");
+        assert_eq!(format!("{:#}", id.fingerprint()), "ZMXV");
+        assert_ne!(next.fingerprint(), id.fingerprint());
+    }
+}
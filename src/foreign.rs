@@ -0,0 +1,112 @@
+use alloc::string::String;
+use core::{
+    fmt::{Debug, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{Error, Segment};
+
+/// A prefixed identifier from another system, such as `cus_NffrFeUfNV2Hib`.
+///
+/// The value is kept as written, since it does not follow souvenir's encoding. Prefixes
+/// are ASCII letters, digits and underscores, and values are ASCII letters, digits and
+/// hyphens; the two are split at the last underscore, so `sk_live_4eC39HqLyjWD` has the
+/// prefix `sk_live`.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ForeignId {
+    prefix: String,
+    value: String,
+}
+
+impl ForeignId {
+    /// Test to see if the provided string is a valid `ForeignId`.
+    pub fn test(value: &str) -> bool {
+        Self::parse(value).is_ok()
+    }
+
+    /// Attempt to parse the provided string into a `ForeignId`.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let (prefix, value) = value.rsplit_once('_').ok_or(Error::InvalidData)?;
+
+        let valid = !prefix.is_empty()
+            && !value.is_empty()
+            && prefix
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'_')
+            && value
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-');
+
+        if !valid {
+            return Err(Error::InvalidData);
+        }
+
+        Ok(Self {
+            prefix: prefix.into(),
+            value: value.into(),
+        })
+    }
+
+    /// Attempt to parse the provided string into a `ForeignId` with the provided prefix.
+    pub fn parse_with_prefix(value: &str, prefix: &'static str) -> Result<Self, Error> {
+        let id = Self::parse(value)?;
+
+        if id.prefix != prefix {
+            return Err(Error::PrefixMismatch {
+                name: prefix,
+                expected: prefix,
+                actual: Segment::new(&id.prefix),
+            });
+        }
+
+        Ok(id)
+    }
+
+    /// Get the prefix of this identifier.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Get the value of this identifier, as written.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Debug for ForeignId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl Display for ForeignId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}_{}", self.prefix, self.value)
+    }
+}
+
+impl FromStr for ForeignId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ForeignId;
+
+    #[test]
+    fn foreign_id_parts() {
+        let id = ForeignId::parse("sk_live_4eC39HqLyjWD").unwrap();
+        assert_eq!(id.prefix(), "sk_live");
+        assert_eq!(id.value(), "4eC39HqLyjWD");
+        assert_eq!(id.to_string(), "sk_live_4eC39HqLyjWD");
+
+        assert!(ForeignId::parse_with_prefix("cus_NffrFeUfNV2Hib", "cus").is_ok());
+        assert!(ForeignId::parse_with_prefix("cus_NffrFeUfNV2Hib", "sub").is_err());
+        assert!(!ForeignId::test("cus_"));
+        assert!(!ForeignId::test("cus_abc.def"));
+    }
+}
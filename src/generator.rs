@@ -0,0 +1,229 @@
+#[cfg(all(feature = "rand", feature = "std"))]
+use crate::config::ConfiguredRng;
+#[cfg(all(feature = "rand", feature = "std"))]
+use crate::time::{to_millis, TIMESTAMP_BITS};
+use crate::{Id, IdBytes, Type};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+#[cfg(all(feature = "rand", feature = "std"))]
+use rand::Rng;
+#[cfg(all(feature = "rand", feature = "std"))]
+use std::time::SystemTime;
+
+/// A source of identifier values.
+///
+/// Generators take `&self` so a single instance can be shared between threads; those
+/// with state use interior mutability.
+pub trait Generator {
+    /// Produce the next identifier value.
+    fn next_value(&self) -> IdBytes;
+}
+
+impl<G: Generator + ?Sized> Generator for &G {
+    fn next_value(&self) -> IdBytes {
+        (**self).next_value()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<G: Generator + ?Sized> Generator for alloc::boxed::Box<G> {
+    fn next_value(&self) -> IdBytes {
+        (**self).next_value()
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<G: Generator + ?Sized> Generator for alloc::sync::Arc<G> {
+    fn next_value(&self) -> IdBytes {
+        (**self).next_value()
+    }
+}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Generate an Id<T> with the next value from the provided generator.
+    ///
+    /// Values reserved by [`Type::RESERVED`] or rejected by [`Type::validate`] are skipped.
+    pub fn generate<G: Generator + ?Sized>(generator: &G) -> Self {
+        loop {
+            let id = Self::new(generator.next_value());
+
+            if id.is_issuable() {
+                #[cfg(feature = "std")]
+                crate::hooks::generated::<T>(core::any::type_name::<G>(), id.as_bytes());
+
+                return id;
+            }
+        }
+    }
+}
+
+/// A generator producing random values from the thread-local random number generator, or
+/// the [configured](crate::Config::rng) source of randomness.
+#[cfg(all(feature = "rand", feature = "std"))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Random;
+
+#[cfg(all(feature = "rand", feature = "std"))]
+impl Generator for Random {
+    fn next_value(&self) -> IdBytes {
+        ConfiguredRng.gen()
+    }
+}
+
+/// A generator producing time-ordered values from the current time and the thread-local
+/// random number generator.
+///
+/// The top 48 bits hold the milliseconds since the Unix epoch, like ULIDs and UUIDv7, or
+/// since the [configured](crate::Config::epoch) epoch, and
+/// the remaining 80 bits are random. Values from the same millisecond are not ordered
+/// relative to each other.
+#[cfg(all(feature = "rand", feature = "std"))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timestamped;
+
+#[cfg(all(feature = "rand", feature = "std"))]
+impl Generator for Timestamped {
+    fn next_value(&self) -> IdBytes {
+        let millis = to_millis(SystemTime::now()) as u128;
+        let random = ConfiguredRng.gen::<u128>() >> TIMESTAMP_BITS;
+
+        (millis << (128 - TIMESTAMP_BITS) | random).to_be_bytes()
+    }
+}
+
+/// A generator producing consecutive values, starting at 1 by default.
+///
+/// Values are counted in the low 64 bits, so they are only unique within a single
+/// generator. This is mostly useful for tests and in-memory stores.
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug)]
+pub struct Sequential {
+    next: AtomicU64,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Sequential {
+    /// Create a new sequential generator starting at 1.
+    pub const fn new() -> Self {
+        Self::starting_at(1)
+    }
+
+    /// Create a new sequential generator starting at the provided value.
+    pub const fn starting_at(value: u64) -> Self {
+        Self {
+            next: AtomicU64::new(value),
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Default for Sequential {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Generator for Sequential {
+    /// # Panics
+    ///
+    /// Panics once the counter reaches `u64::MAX`.
+    fn next_value(&self) -> IdBytes {
+        let value = self
+            .next
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |value| {
+                value.checked_add(1)
+            })
+            .unwrap_or_else(|_| {
+                #[cfg(feature = "metrics")]
+                crate::metrics::exhausted("sequential");
+
+                panic!("sequential generator to not be exhausted")
+            });
+
+        (value as u128).to_be_bytes()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Generator, Id, IdBytes, Sequential, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn sequential_values() {
+        let generator = Sequential::new();
+        let first: Id<User> = Id::generate(&generator);
+        let second: Id<User> = Id::generate(&generator);
+
+        assert_eq!(first.to_u128(), 1);
+        assert_eq!(second.to_u128(), 2);
+
+        let generator: &dyn Generator = &Sequential::starting_at(u64::MAX - 1);
+        assert_eq!(
+            Id::<User>::generate(generator).to_u128(),
+            u64::MAX as u128 - 1
+        );
+    }
+
+    struct Actor;
+
+    impl Type for Actor {
+        const PREFIX: &'static str = "actor";
+        const RESERVED: &'static [IdBytes] = &[1u128.to_be_bytes(), 2u128.to_be_bytes()];
+    }
+
+    #[test]
+    fn reserved_values_skipped() {
+        let generator = Sequential::new();
+        let id: Id<Actor> = Id::generate(&generator);
+
+        assert_eq!(id.to_u128(), 3);
+        assert!(!id.is_reserved());
+        assert!(Id::<Actor>::from(1u128).is_reserved());
+        assert!(!Id::<User>::from(1u128).is_reserved());
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", feature = "std"))]
+    fn invalid_values_skipped() {
+        use crate::{Error, Random};
+
+        struct Account;
+
+        impl Type for Account {
+            const PREFIX: &'static str = "acct";
+
+            fn validate(bytes: &IdBytes) -> Result<(), Error> {
+                match bytes[0] & 0x80 {
+                    0 => Ok(()),
+                    _ => Err(Error::InvalidData),
+                }
+            }
+        }
+
+        for _ in 0..1000 {
+            for id in [Id::<Account>::generate(&Random), Id::<Account>::random()] {
+                assert_eq!(Id::<Account>::parse(&id.to_string()).ok(), Some(id));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", feature = "std"))]
+    fn timestamped_values() {
+        use crate::Timestamped;
+        use std::time::SystemTime;
+
+        let start = Id::<User>::min_for_timestamp(SystemTime::now());
+        let id = Id::<User>::generate(&Timestamped);
+        let end = Id::<User>::max_for_timestamp(SystemTime::now());
+
+        assert!(start <= id && id <= end);
+    }
+}
@@ -1,23 +1,66 @@
-use std::{
+use alloc::string::{String, ToString};
+use core::{
     fmt::{Debug, Display, Formatter},
     str::FromStr,
 };
+use smol_str::SmolStr;
 
-use crate::{encoding::parse_base32, Error, Id, Type};
+#[cfg(feature = "std")]
+use crate::environment;
+use crate::{encoding::parse_value, Error, Id, Type};
 
 impl Type for () {
     const PREFIX: &'static str = "";
 }
 
+/// An identifier with a prefix only known at runtime.
+///
+/// Prefixes of up to 23 bytes are stored inline and longer ones are shared, so cloning a
+/// `Generic` never allocates. Identifiers are ordered by prefix, then by value.
+///
+/// In databases, `Generic` is stored as `TEXT` in its `Display` form, for columns which
+/// may refer to identifiers of any type.
 #[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(::diesel::AsExpression, ::diesel::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = ::diesel::sql_types::Text))]
 pub struct Generic {
-    prefix: String,
+    prefix: SmolStr,
     value: Id<()>,
 }
 
+/// The maximum length of a `Generic` prefix.
+const MAX_PREFIX_LENGTH: usize = 63;
+
 impl Generic {
-    /// Create a new `Id<T>` with the following underlying value.
-    pub fn new(prefix: impl Into<String>, value: [u8; 16]) -> Self {
+    /// Create a new `Generic` with the provided prefix and underlying value.
+    ///
+    /// Prefixes are up to 63 ASCII letters and digits; anything else returns
+    /// [`Error::InvalidPrefix`].
+    pub fn new(prefix: impl Into<SmolStr>, value: [u8; 16]) -> Result<Self, Error> {
+        let prefix = prefix.into();
+
+        if prefix.len() > MAX_PREFIX_LENGTH || !prefix.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return Err(Error::InvalidPrefix);
+        }
+
+        Ok(Self::new_unchecked(prefix, value))
+    }
+
+    /// Generate a new random `Generic` with the provided prefix.
+    ///
+    /// See [`Generic::new`] for the valid prefixes.
+    #[cfg(all(feature = "rand", feature = "std"))]
+    pub fn random(prefix: impl Into<SmolStr>) -> Result<Self, Error> {
+        use rand::Rng;
+
+        Self::new(prefix, crate::config::ConfiguredRng.gen())
+    }
+
+    /// Create a new `Generic` without checking the prefix.
+    pub(crate) fn new_unchecked(prefix: impl Into<SmolStr>, value: [u8; 16]) -> Self {
         Self {
             prefix: prefix.into(),
             value: Id::new(value),
@@ -26,7 +69,7 @@ impl Generic {
 
     /// Get the data value of the identifier.
     pub fn as_bytes(&self) -> &[u8; 16] {
-        &self.value.as_bytes()
+        self.value.as_bytes()
     }
 
     /// Get the data value of the identifier.
@@ -50,13 +93,19 @@ impl Generic {
     }
 
     /// Attempt to parse the provided string into a `Generic`.
+    ///
+    /// Any prefix without an underscore is accepted, including those rejected by
+    /// [`Generic::new`], so that every `Id<T>` parses as a `Generic`.
     pub fn parse(value: &str) -> Result<Self, Error> {
+        #[cfg(feature = "std")]
+        let value = crate::config::current().trim(value);
+
         let (prefix, value) = value.split_once('_').ok_or(Error::InvalidData)?;
 
-        Ok(Self {
-            prefix: prefix.to_owned(),
-            value: Id::new(parse_base32(value)?),
-        })
+        #[cfg(feature = "std")]
+        let value = environment::strip(value)?;
+
+        Ok(Self::new_unchecked(prefix, parse_value(value)?))
     }
 
     /// Get the prefix of this identifier.
@@ -71,14 +120,17 @@ impl Generic {
 }
 
 impl Debug for Generic {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self)
     }
 }
 
 impl Display for Generic {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}", self.prefix, self.value)
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match f.alternate() {
+            true => write!(f, "{}{:#}", self.prefix, self.value),
+            false => write!(f, "{}{}", self.prefix, self.value),
+        }
     }
 }
 
@@ -90,6 +142,28 @@ impl FromStr for Generic {
     }
 }
 
+impl TryFrom<&str> for Generic {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl TryFrom<String> for Generic {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<Generic> for String {
+    fn from(value: Generic) -> Self {
+        value.to_string()
+    }
+}
+
 impl From<Generic> for u128 {
     fn from(value: Generic) -> Self {
         value.to_u128()
@@ -111,4 +185,30 @@ mod test {
         let id = Generic::parse("test_7zzzzzzzzzzzzzzzzzzzzzzzzz").unwrap();
         assert_eq!(id.to_string(), "test_7zzzzzzzzzzzzzzzzzzzzzzzzz");
     }
+
+    #[test]
+    fn generic_ordering() {
+        let mut ids = [
+            Generic::new("user", [0; 16]).unwrap(),
+            Generic::new("org", [1; 16]).unwrap(),
+            Generic::new("org", [0; 16]).unwrap(),
+        ];
+        ids.sort();
+
+        assert_eq!(ids[0], Generic::new("org", [0; 16]).unwrap());
+        assert_eq!(ids[1], Generic::new("org", [1; 16]).unwrap());
+        assert_eq!(ids[2], Generic::new("user", [0; 16]).unwrap());
+    }
+
+    #[test]
+    fn generic_prefix_validation() {
+        assert!(Generic::new("user", [0; 16]).is_ok());
+        assert!(Generic::new("", [0; 16]).is_ok());
+        assert!(Generic::new("a/b", [0; 16]).is_err());
+        assert!(Generic::new("a".repeat(64), [0; 16]).is_err());
+
+        let id = Generic::parse("a-b_00000000000000000000000000").unwrap();
+        assert_eq!(id.prefix(), "a-b");
+        assert_eq!(Generic::parse(&id.to_string()).unwrap(), id);
+    }
 }
@@ -0,0 +1,173 @@
+use crate::{Id, Type};
+
+/// The 32-bit MurmurHash2 variant used by Kafka's default partitioner.
+pub(crate) fn murmur2(data: &[u8]) -> u32 {
+    const SEED: u32 = 0x9747b28c;
+    const M: u32 = 0x5bd1e995;
+    const R: u32 = 24;
+
+    let mut h = SEED ^ data.len() as u32;
+    let mut chunks = data.chunks_exact(4);
+
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes(chunk.try_into().unwrap());
+        k = k.wrapping_mul(M);
+        k ^= k >> R;
+        k = k.wrapping_mul(M);
+
+        h = h.wrapping_mul(M);
+        h ^= k;
+    }
+
+    let tail = chunks.remainder();
+
+    if !tail.is_empty() {
+        for (i, b) in tail.iter().enumerate() {
+            h ^= (*b as u32) << (8 * i);
+        }
+
+        h = h.wrapping_mul(M);
+    }
+
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^= h >> 15;
+
+    h
+}
+
+/// The SplitMix64 finalizer, a fast bijective mix of all 64 bits.
+pub(crate) fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Get the partition, out of `partitions`, for records keyed by this identifier.
+    ///
+    /// This is the partition Kafka's default partitioner picks for a record whose key is
+    /// [`Id::as_bytes`], so producers in any language using that key agree with it. The
+    /// hash only depends on the 16 bytes of the identifier, never its prefix or the
+    /// environment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `partitions` is zero.
+    pub fn partition(self, partitions: u32) -> u32 {
+        assert!(partitions > 0, "partition count to be non-zero");
+
+        (murmur2(self.as_bytes()) & 0x7fffffff) % partitions
+    }
+
+    /// Get the rollout percentile of this identifier, from 0 to 99.
+    ///
+    /// Percentiles are uniformly distributed and stable across releases: the percentile
+    /// is `(murmur2(bytes) * 100) >> 32`, using the same hash as [`Id::partition`], so
+    /// services in other languages can compute it too.
+    pub fn percentile(self) -> u8 {
+        ((murmur2(self.as_bytes()) as u64 * 100) >> 32) as u8
+    }
+
+    /// Check if this identifier falls within a gradual rollout to `percent` percent of
+    /// identifiers.
+    ///
+    /// Raising the percentage only ever adds identifiers to the rollout. Every rollout
+    /// buckets identifiers the same way, so two rollouts at 10% reach the same entities.
+    pub fn in_rollout(self, percent: u8) -> bool {
+        self.percentile() < percent
+    }
+
+    /// Get a 64-bit hash of this identifier which is the same on every platform and in
+    /// every release, for consistent routing and on-disk data structures.
+    ///
+    /// Unlike the `Hash` implementation, which feeds a hasher that may be randomly seeded,
+    /// this is a fixed function of the 16 bytes of the identifier: splitting the value into
+    /// its `high` and `low` 64 bits, the hash is `mix64(high ^ mix64(low))`, where `mix64`
+    /// is the SplitMix64 finalizer. The prefix and environment are not hashed.
+    pub fn stable_hash(self) -> u64 {
+        let value = self.to_u128();
+
+        mix64((value >> 64) as u64 ^ mix64(value as u64))
+    }
+
+    /// Get `k` hash values of this identifier, for inserting it into or querying a bloom or
+    /// cuckoo filter.
+    ///
+    /// The values are derived from two 64-bit hashes of the value with the double hashing
+    /// scheme of Kirsch and Mitzenmacher, `h1 + i * h2`, which gives filters the same false
+    /// positive rate as `k` independent hash functions. They only depend on the 16 bytes
+    /// of the identifier and are stable across releases, so filters can be persisted and
+    /// shared between processes. Reduce each value modulo the filter size to get a bit
+    /// index.
+    pub fn bloom_hashes(self, k: usize) -> impl Iterator<Item = u64> {
+        let value = self.to_u128();
+        let (high, low) = ((value >> 64) as u64, value as u64);
+
+        let h1 = self.stable_hash();
+        // An odd step visits every residue modulo a power of two filter size.
+        let h2 = mix64(low ^ mix64(high).rotate_left(32)) | 1;
+
+        (0..k as u64).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::murmur2;
+    use crate::{Id, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn kafka_murmur2() {
+        // Test vectors from Kafka's `UtilsTest`.
+        assert_eq!(murmur2(b"21") as i32, -973932308);
+        assert_eq!(murmur2(b"foobar") as i32, -790332482);
+        assert_eq!(murmur2(b"a-little-bit-long-string") as i32, -985981536);
+        assert_eq!(murmur2(b"a-little-bit-longer-string") as i32, -1486304829);
+        assert_eq!(murmur2(b"abc") as i32, 479470107);
+    }
+
+    #[test]
+    fn rollout_buckets() {
+        let mut counts = [0; 100];
+
+        for i in 0..100_000u128 {
+            let id = Id::<User>::from(i);
+            counts[id.percentile() as usize] += 1;
+
+            assert!(!id.in_rollout(0));
+            assert!(id.in_rollout(100));
+            assert_eq!(id.in_rollout(25), id.percentile() < 25);
+        }
+
+        assert!(counts.iter().all(|&count| (800..1200).contains(&count)));
+    }
+
+    #[test]
+    fn stable_hash_values() {
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+
+        assert_eq!(id.stable_hash(), 0xfd3b_b7fc_207b_9d2f);
+        assert_eq!(Id::<User>::from(0u128).stable_hash(), 0);
+        assert_eq!(id.bloom_hashes(1).next(), Some(id.stable_hash()));
+    }
+
+    #[test]
+    fn bloom_hash_values() {
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+        let other: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2m").unwrap();
+
+        let hashes: Vec<u64> = id.bloom_hashes(4).collect();
+        assert_eq!(hashes.len(), 4);
+        assert_eq!(id.bloom_hashes(4).collect::<Vec<_>>(), hashes);
+        assert_eq!(id.bloom_hashes(2).collect::<Vec<_>>(), hashes[..2]);
+        assert!(hashes.windows(2).all(|pair| pair[0] != pair[1]));
+        assert_ne!(other.bloom_hashes(4).collect::<Vec<_>>(), hashes);
+    }
+}
@@ -0,0 +1,141 @@
+//! Expiring, signed identifier tokens.
+//!
+//! An [`ExpiringId`] packs an identifier, an expiry time and an HMAC-SHA256 tag into one
+//! opaque token, for magic links, invite URLs and other places where an id is handed out
+//! for a limited time:
+//!
+//! ```
+//! use souvenir::hmac::ExpiringId;
+//! use souvenir::{Id, Type};
+//! use std::time::{Duration, SystemTime};
+//!
+//! struct Invite;
+//!
+//! impl Type for Invite {
+//!     const PREFIX: &'static str = "inv";
+//! }
+//!
+//! let key = b"server secret";
+//! let id: Id<Invite> = Id::parse("inv_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+//! let now = SystemTime::now();
+//!
+//! let token = ExpiringId::new(id, now + Duration::from_secs(3600)).sign(key);
+//! let verified = ExpiringId::<Invite>::verify(&token, key, now).unwrap();
+//! assert_eq!(verified.id(), id);
+//!
+//! assert!(ExpiringId::<Invite>::verify(&token, key, now + Duration::from_secs(7200)).is_err());
+//! ```
+//!
+//! The tag covers the prefix as well, so a token issued for one type never verifies as
+//! another. The identifier and expiry are signed, not encrypted, so they can be read by
+//! anyone holding the token.
+
+use crate::encoding::{decode_base32, encode_base32};
+use crate::{Error, Id, IdBytes, Type};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Number of bytes of the HMAC-SHA256 tag kept in a token.
+const TAG_LEN: usize = 16;
+
+/// Number of bytes of the signed payload, the identifier followed by the expiry.
+const PAYLOAD_LEN: usize = size_of::<IdBytes>() + size_of::<u64>();
+
+/// An `Id<T>` that is only valid until an expiry time.
+pub struct ExpiringId<T: Type + ?Sized> {
+    id: Id<T>,
+    expires_at: u64,
+}
+
+impl<T: Type + ?Sized> ExpiringId<T> {
+    /// Create a new expiring identifier, truncating the expiry to whole seconds.
+    pub fn new(id: Id<T>, expires_at: SystemTime) -> Self {
+        Self {
+            id,
+            expires_at: expires_at
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or(Duration::ZERO)
+                .as_secs(),
+        }
+    }
+
+    /// Get the identifier.
+    pub fn id(&self) -> Id<T> {
+        self.id
+    }
+
+    /// Get the time after which the identifier is no longer valid.
+    pub fn expires_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.expires_at)
+    }
+
+    /// Sign this identifier with the provided key, producing an opaque token.
+    pub fn sign(&self, key: &[u8]) -> String {
+        let payload = self.payload();
+
+        let mut data = Vec::with_capacity(PAYLOAD_LEN + TAG_LEN);
+        data.extend_from_slice(&payload);
+        data.extend_from_slice(&Self::mac(key, &payload).finalize().into_bytes()[..TAG_LEN]);
+
+        encode_base32(&data)
+    }
+
+    /// Verify a token produced by [`ExpiringId::sign`], checking its tag against the
+    /// provided key and its expiry against `now`.
+    pub fn verify(token: &str, key: &[u8], now: SystemTime) -> Result<Self, Error> {
+        let data = decode_base32(token)?;
+        if data.len() != PAYLOAD_LEN + TAG_LEN {
+            return Err(Error::InvalidData);
+        }
+
+        let (payload, tag) = data.split_at(PAYLOAD_LEN);
+        Self::mac(key, payload)
+            .verify_truncated_left(tag)
+            .map_err(|_| Error::InvalidSignature)?;
+
+        let (id, expires_at) = payload.split_at(size_of::<IdBytes>());
+        let value = Self {
+            id: Id::try_from(id)?,
+            expires_at: u64::from_be_bytes(expires_at.try_into().map_err(|_| Error::InvalidData)?),
+        };
+
+        if now >= value.expires_at() {
+            return Err(Error::Expired);
+        }
+
+        Ok(value)
+    }
+
+    fn payload(&self) -> [u8; PAYLOAD_LEN] {
+        let mut payload = [0; PAYLOAD_LEN];
+        payload[..size_of::<IdBytes>()].copy_from_slice(self.id.as_bytes());
+        payload[size_of::<IdBytes>()..].copy_from_slice(&self.expires_at.to_be_bytes());
+        payload
+    }
+
+    fn mac(key: &[u8], payload: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("hmac to accept any key length");
+        mac.update(T::PREFIX.as_bytes());
+        mac.update(&[0]);
+        mac.update(payload);
+        mac
+    }
+}
+
+impl<T: Type + ?Sized> Clone for ExpiringId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Type + ?Sized> Copy for ExpiringId<T> {}
+
+impl<T: Type + ?Sized> std::fmt::Debug for ExpiringId<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExpiringId")
+            .field("id", &self.id)
+            .field("expires_at", &self.expires_at())
+            .finish()
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
+
+use crate::{IdBytes, Type};
+
+type Hook = Arc<dyn Fn(&Generated) + Send + Sync>;
+
+static HOOKS: RwLock<Vec<Hook>> = RwLock::new(Vec::new());
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Details of a newly generated identifier, passed to generation hooks.
+#[derive(Clone, Copy, Debug)]
+pub struct Generated {
+    prefix: &'static str,
+    name: &'static str,
+    generator: &'static str,
+    value: IdBytes,
+    time: SystemTime,
+}
+
+impl Generated {
+    /// Get the prefix of the identifier's type.
+    pub fn prefix(&self) -> &'static str {
+        self.prefix
+    }
+
+    /// Get the name of the identifier's type, from [`Type::NAME`].
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the type name of the generator or random number generator which produced
+    /// the value, such as `souvenir::generator::Sequential`.
+    pub fn generator(&self) -> &'static str {
+        self.generator
+    }
+
+    /// Get the generated value.
+    pub fn value(&self) -> IdBytes {
+        self.value
+    }
+
+    /// Get the time the identifier was generated.
+    pub fn time(&self) -> SystemTime {
+        self.time
+    }
+}
+
+/// Register a hook called with every identifier generated in the process, by
+/// `Id::generate`, `Id::generate_async`, `Id::random` and `Id::random_with`, for auditing
+/// issuance or capturing identifiers in tests.
+///
+/// Hooks run synchronously on the generating thread, in the order they were added, and
+/// can't be removed. While no hooks are registered, generation only pays for a single
+/// atomic load. `Generic` identifiers are not reported.
+pub fn add_generation_hook(hook: impl Fn(&Generated) + Send + Sync + 'static) {
+    HOOKS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(Arc::new(hook));
+
+    ACTIVE.store(true, Ordering::Release);
+}
+
+/// Report a generated value to the registered hooks, and to metrics.
+pub(crate) fn generated<T: Type + ?Sized>(generator: &'static str, value: &IdBytes) {
+    #[cfg(feature = "metrics")]
+    crate::metrics::generated(T::PREFIX);
+
+    if !ACTIVE.load(Ordering::Acquire) {
+        return;
+    }
+
+    // Clone the hooks out of the lock, so hooks can generate identifiers themselves.
+    let hooks = HOOKS.read().unwrap_or_else(|e| e.into_inner()).clone();
+
+    let generated = Generated {
+        prefix: T::PREFIX,
+        name: T::NAME,
+        generator,
+        value: *value,
+        time: SystemTime::now(),
+    };
+
+    for hook in hooks {
+        hook(&generated);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::add_generation_hook;
+    use crate::{Id, Sequential, Type};
+    use std::sync::{Arc, Mutex};
+
+    struct Hooked;
+
+    impl Type for Hooked {
+        const PREFIX: &'static str = "hooked";
+    }
+
+    #[test]
+    fn generation_hooks() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let captured = seen.clone();
+
+        add_generation_hook(move |generated| {
+            if generated.prefix() == "hooked" {
+                captured
+                    .lock()
+                    .unwrap()
+                    .push((generated.generator(), generated.value()));
+            }
+        });
+
+        let id = Id::<Hooked>::generate(&Sequential::new());
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [("souvenir::generator::Sequential", id.to_bytes())]
+        );
+    }
+}
@@ -1,8 +1,16 @@
-use crate::encoding::{parse_base32, stringify_base32};
-use crate::{Error, Type};
-use std::fmt::{Debug, Display, Formatter};
-use std::marker::PhantomData;
-use std::str::FromStr;
+#[cfg(feature = "alloc")]
+use crate::encoding::{
+    decode_base64, encode_base64, parse_base32, percent_decode, percent_encode, stringify_base32,
+};
+use crate::encoding::{format_base32, parse_value};
+#[cfg(feature = "std")]
+use crate::environment::{self, environment};
+use crate::{Error, Segment, Type};
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, string::ToString};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
 
 /// Type of the underlying data stored in an `Id`.
 pub type IdBytes = [u8; 16];
@@ -26,32 +34,70 @@ pub type IdBytes = [u8; 16];
 /// let id2: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
 /// assert_eq!(id2.to_string(), "user_02v58c5a3fy30k560qrtg4rb2k");
 /// ```
+///
+/// `Id<T>` is `repr(transparent)` over [`IdBytes`], so it has the size and alignment of
+/// `[u8; 16]` and holds the value in big-endian order. Across a C ABI it can be passed
+/// as `uint8_t[16]`.
+#[repr(transparent)]
 #[cfg_attr(
     feature = "diesel",
     derive(::diesel::AsExpression, ::diesel::FromSqlRow)
 )]
 #[cfg_attr(feature = "diesel", diesel(sql_type = ::diesel::sql_types::Int8))]
+#[cfg_attr(feature = "juniper", derive(::juniper::GraphQLScalar))]
+#[cfg_attr(
+    feature = "juniper",
+    graphql(
+        description = "A prefixed identifier.",
+        with = crate::juniper,
+        parse_token(String)
+    )
+)]
 pub struct Id<T: Type + ?Sized> {
-    marker: PhantomData<T>,
+    marker: PhantomData<fn() -> T>,
     value: IdBytes,
 }
 
 impl<T: Type + ?Sized> Id<T> {
     /// Create a new `Id<T>` with the following underlying value.
-    pub fn new(value: [u8; 16]) -> Self {
+    pub const fn new(value: [u8; 16]) -> Self {
         Self {
             marker: PhantomData,
             value,
         }
     }
 
+    /// Create a new `Id<T>` with the following underlying value, checking it with
+    /// [`Type::validate`].
+    pub fn new_checked(value: [u8; 16]) -> Result<Self, Error> {
+        T::validate(&value)?;
+        Ok(Self::new(value))
+    }
+
+    /// Check if this identifier is one of the values in [`Type::RESERVED`].
+    pub fn is_reserved(self) -> bool {
+        T::RESERVED.contains(&self.value)
+    }
+
+    /// Check if a generated identifier can be handed out, being neither reserved nor
+    /// rejected by [`Type::validate`].
+    pub(crate) fn is_issuable(self) -> bool {
+        !self.is_reserved() && T::validate(&self.value).is_ok()
+    }
+
     /// Get the data value of the identifier.
     pub fn as_bytes(&self) -> &[u8; 16] {
         &self.value
     }
 
+    /// Get mutable access to the data value of the identifier.
+    #[cfg(feature = "zeroize")]
+    pub(crate) fn as_bytes_mut(&mut self) -> &mut [u8; 16] {
+        &mut self.value
+    }
+
     /// Get the data value of the identifier.
-    pub fn to_bytes(self) -> [u8; 16] {
+    pub const fn to_bytes(self) -> [u8; 16] {
         self.value
     }
 
@@ -71,17 +117,82 @@ impl<T: Type + ?Sized> Id<T> {
     }
 
     /// Attempt to parse the provided string into an `Id<T>`.
+    ///
+    /// The value may be written entirely in lowercase, or entirely in uppercase, unless
+    /// parsing is [lenient](crate::Config::lenient).
     pub fn parse(value: &str) -> Result<Self, Error> {
+        let result = Self::parse_untracked(value);
+
+        #[cfg(feature = "metrics")]
+        if let Err(error) = &result {
+            crate::metrics::parse_failed(T::PREFIX, error);
+        }
+
+        result
+    }
+
+    /// Parse an identifier without recording failures in metrics.
+    fn parse_untracked(value: &str) -> Result<Self, Error> {
+        #[cfg(feature = "std")]
+        let value = crate::config::current().trim(value);
+
         let (prefix, value) = value.split_once('_').ok_or(Error::InvalidData)?;
 
         if prefix != T::PREFIX {
             return Err(Error::PrefixMismatch {
+                name: T::NAME,
+                expected: T::PREFIX,
+                actual: Segment::new(prefix),
+            });
+        }
+
+        #[cfg(feature = "std")]
+        let value = environment::strip(value)?;
+
+        Self::new_checked(parse_value(value)?)
+    }
+
+    /// Encode this identifier for use as a URL path segment, query value or file name.
+    ///
+    /// Values, environment tags and the `_` separator only use characters from the
+    /// RFC 3986 unreserved set, so for prefixes made of ASCII letters and digits this is
+    /// the same as the `Display` form. Any other prefix bytes are percent-encoded. The
+    /// `Cursor` and `ExpiringId` tokens are always URL- and filename-safe; Relay global
+    /// ids use standard base64 by convention and are not.
+    #[cfg(feature = "alloc")]
+    pub fn to_url_component(self) -> String {
+        percent_encode(&self.to_string())
+    }
+
+    /// Attempt to parse a percent-encoded URL component into an `Id<T>`, for routers
+    /// which do not decode path segments themselves.
+    #[cfg(feature = "alloc")]
+    pub fn parse_url_component(value: &str) -> Result<Self, Error> {
+        Self::parse(&percent_decode(value)?)
+    }
+
+    /// Encode this identifier as a Relay global ID, `base64("prefix:value")`.
+    #[cfg(feature = "alloc")]
+    pub fn to_global_id(self) -> String {
+        let value = stringify_base32(self.value).expect("id value to stringify correctly");
+        encode_base64(format!("{}:{}", T::PREFIX, value).as_bytes())
+    }
+
+    /// Attempt to parse a Relay global ID into an `Id<T>`.
+    #[cfg(feature = "alloc")]
+    pub fn from_global_id(value: &str) -> Result<Self, Error> {
+        let decoded = String::from_utf8(decode_base64(value)?).map_err(|_| Error::InvalidData)?;
+        let (prefix, value) = decoded.split_once(':').ok_or(Error::InvalidData)?;
+
+        if prefix != T::PREFIX {
+            return Err(Error::PrefixMismatch {
+                name: T::NAME,
                 expected: T::PREFIX,
-                actual: String::from(prefix),
+                actual: Segment::new(prefix),
             });
         }
 
-        Ok(Self::new(parse_base32(value)?))
+        Self::new_checked(parse_base32(value)?)
     }
 
     /// Get the prefix of this identifier.
@@ -89,6 +200,21 @@ impl<T: Type + ?Sized> Id<T> {
         T::PREFIX
     }
 
+    /// Get a short form of this identifier for human-oriented log lines and badges.
+    ///
+    /// The short form is the `Display` form, including any environment tag and uppercasing,
+    /// cut to exactly the first 6 characters of the value, with a trailing `…` marking the
+    /// truncation, e.g. `user_02v58c…`. It is not unique and cannot be parsed back into an
+    /// `Id<T>`. Time-ordered identifiers created
+    /// close together share their leading characters, so prefer the full form wherever
+    /// identifiers need to be told apart.
+    #[cfg(feature = "alloc")]
+    pub fn short(self) -> String {
+        // Keep the first 6 of the 26 value characters at the end of the full form.
+        let full = self.to_string();
+        format!("{}…", &full[..full.len() - 20])
+    }
+
     /// Cast this Id into an Id of a different type.
     pub const fn cast<U: Type + ?Sized>(self) -> Id<U> {
         Id {
@@ -106,9 +232,9 @@ impl<T: Type + ?Sized> Clone for Id<T> {
     }
 }
 
-impl<T: Type + ?Sized> std::hash::Hash for Id<T> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.value.hash(state);
+impl<T: Type + ?Sized> core::hash::Hash for Id<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_u128(self.to_u128());
     }
 }
 
@@ -120,32 +246,116 @@ impl<T: Type + ?Sized> PartialEq for Id<T> {
 
 impl<T: Type + ?Sized> Eq for Id<T> {}
 
+/// Compare against the `Display` form of the identifier, with the value in either case,
+/// without allocating.
+impl<T: Type + ?Sized> PartialEq<str> for Id<T> {
+    fn eq(&self, other: &str) -> bool {
+        let Some(value) = other
+            .strip_prefix(T::PREFIX)
+            .and_then(|value| value.strip_prefix('_'))
+        else {
+            return false;
+        };
+
+        #[cfg(feature = "std")]
+        let value = match environment() {
+            Some(environment) => match value
+                .strip_prefix(environment)
+                .and_then(|value| value.strip_prefix('_'))
+            {
+                Some(value) => value,
+                None => return false,
+            },
+            None => value,
+        };
+
+        let mut formatted = format_base32(self.value);
+
+        if value.as_bytes() == formatted {
+            return true;
+        }
+
+        formatted.make_ascii_uppercase();
+        value.as_bytes() == formatted
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq<&str> for Id<T> {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq<Id<T>> for str {
+    fn eq(&self, other: &Id<T>) -> bool {
+        other == self
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq<Id<T>> for &str {
+    fn eq(&self, other: &Id<T>) -> bool {
+        other == *self
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq<u128> for Id<T> {
+    fn eq(&self, other: &u128) -> bool {
+        self.to_u128() == *other
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq<i128> for Id<T> {
+    fn eq(&self, other: &i128) -> bool {
+        self.to_i128() == *other
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq<Id<T>> for u128 {
+    fn eq(&self, other: &Id<T>) -> bool {
+        other == self
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq<Id<T>> for i128 {
+    fn eq(&self, other: &Id<T>) -> bool {
+        other == self
+    }
+}
+
 impl<T: Type + ?Sized> PartialOrd for Id<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         self.cmp(other).into()
     }
 }
 
 impl<T: Type + ?Sized> Ord for Id<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.value.cmp(&other.value)
     }
 }
 
 impl<T: Type + ?Sized> Debug for Id<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self)
     }
 }
 
 impl<T: Type + ?Sized> Display for Id<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}_{}",
-            T::PREFIX,
-            stringify_base32(self.value).expect("id value to stringify correctly")
-        )
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}_", T::PREFIX)?;
+
+        #[cfg(feature = "std")]
+        if let Some(environment) = environment() {
+            write!(f, "{}_", environment)?;
+        }
+
+        let mut value = format_base32(self.value);
+
+        if T::UPPERCASE || f.alternate() {
+            value.make_ascii_uppercase();
+        }
+
+        f.write_str(core::str::from_utf8(&value).expect("only ascii bytes should be in the buffer"))
     }
 }
 
@@ -197,6 +407,197 @@ impl<T: Type + ?Sized> TryFrom<&[u8]> for Id<T> {
     type Error = Error;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        Ok(Self::new(value.try_into().map_err(|_| Error::InvalidData)?))
+        Self::new_checked(value.try_into().map_err(|_| Error::InvalidData)?)
+    }
+}
+
+impl<T: Type + ?Sized> TryFrom<&str> for Id<T> {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Type + ?Sized> TryFrom<String> for Id<T> {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Type + ?Sized> From<Id<T>> for String {
+    fn from(value: Id<T>) -> Self {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Cursor, Error, Id, IdBytes, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    struct Workspace;
+
+    impl Type for Workspace {
+        const PREFIX: &'static str = "ws";
+        const NAME: &'static str = "Workspace";
+    }
+
+    struct Account;
+
+    impl Type for Account {
+        const PREFIX: &'static str = "acct";
+
+        fn validate(bytes: &IdBytes) -> Result<(), Error> {
+            match bytes[0] & 0x80 {
+                0 => Ok(()),
+                _ => Err(Error::InvalidData),
+            }
+        }
+    }
+
+    #[test]
+    fn type_validation() {
+        assert!(Id::<Account>::parse("acct_3zzzzzzzzzzzzzzzzzzzzzzzzz").is_ok());
+        assert!(Id::<Account>::parse("acct_4zzzzzzzzzzzzzzzzzzzzzzzzz").is_err());
+        assert!(Id::<Account>::new_checked([0xff; 16]).is_err());
+        assert!(Id::<Account>::try_from(&[0x7f; 16][..]).is_ok());
+    }
+
+    #[test]
+    fn type_name_in_errors() {
+        let error = Id::<Workspace>::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected Workspace identifier `ws_…`, found prefix `user`"
+        );
+
+        let error = Id::<User>::parse("ws_02v58c5a3fy30k560qrtg4rb2k").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected user identifier `user_…`, found prefix `ws`"
+        );
+    }
+
+    #[test]
+    fn global_id_round_trip() {
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+        let global = id.to_global_id();
+
+        assert_eq!(global, "dXNlcjowMnY1OGM1YTNmeTMwazU2MHFydGc0cmIyaw==");
+        assert_eq!(Id::<User>::from_global_id(&global).unwrap(), id);
+    }
+
+    #[test]
+    fn url_safe() {
+        let is_safe = |value: &str| {
+            value
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~'))
+        };
+
+        for _ in 0..1000 {
+            let id: Id<User> = Id::new(rand::random());
+            assert!(is_safe(&id.to_string()));
+            assert!(is_safe(&id.to_url_component()));
+            assert!(is_safe(&Cursor::with_extra(id, [0xff, 0x00]).to_string()));
+            assert_eq!(Id::parse_url_component(&id.to_url_component()).unwrap(), id);
+        }
+    }
+
+    #[test]
+    fn url_component_escapes_prefix() {
+        struct Path;
+
+        impl Type for Path {
+            const PREFIX: &'static str = "a/b";
+        }
+
+        let id: Id<Path> = Id::new([0; 16]);
+        assert_eq!(id.to_url_component(), "a%2Fb_00000000000000000000000000");
+        assert_eq!(
+            Id::<Path>::parse_url_component("a%2Fb_00000000000000000000000000").unwrap(),
+            id
+        );
+    }
+
+    #[test]
+    fn auto_traits() {
+        fn assert_traits<T: Send + Sync + Unpin>() {}
+
+        // a type which is none of `Send`, `Sync` or `Unpin`
+        struct Pinned(
+            std::marker::PhantomData<*const ()>,
+            std::marker::PhantomPinned,
+        );
+
+        impl Type for Pinned {
+            const PREFIX: &'static str = "pin";
+        }
+
+        assert_traits::<Id<Pinned>>();
+    }
+
+    #[test]
+    fn short_form() {
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+        assert_eq!(id.short(), "user_02v58c…");
+    }
+
+    #[test]
+    fn string_equality() {
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+
+        assert_eq!(id, "user_02v58c5a3fy30k560qrtg4rb2k");
+        assert_eq!("user_02v58c5a3fy30k560qrtg4rb2k", id);
+        assert_ne!(id, "user_02v58c5a3fy30k560qrtg4rb2m");
+        assert_ne!(id, "org_02v58c5a3fy30k560qrtg4rb2k");
+        assert_ne!(id, "user02v58c5a3fy30k560qrtg4rb2k");
+    }
+
+    #[test]
+    fn uppercase_values() {
+        struct Label;
+
+        impl Type for Label {
+            const PREFIX: &'static str = "lbl";
+            const UPPERCASE: bool = true;
+        }
+
+        let id: Id<Label> = Id::parse("lbl_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+        assert_eq!(id.to_string(), "lbl_02V58C5A3FY30K560QRTG4RB2K");
+        assert_eq!(id.short(), "lbl_02V58C…");
+        assert_eq!(Id::parse(&id.to_string()).unwrap(), id);
+        assert_eq!(id, "lbl_02v58c5a3fy30k560qrtg4rb2k");
+
+        let id: Id<User> = id.cast();
+        assert_eq!(format!("{:#}", id), "user_02V58C5A3FY30K560QRTG4RB2K");
+        assert_eq!(id, "user_02V58C5A3FY30K560QRTG4RB2K");
+        assert!(Id::<User>::parse("user_02V58C5A3FY30K560QRTG4RB2k").is_err());
+    }
+
+    #[test]
+    fn integer_equality() {
+        let id: Id<User> = Id::from(u128::MAX);
+
+        assert_eq!(id, u128::MAX);
+        assert_eq!(-1i128, id);
+        assert_ne!(id, 0u128);
+    }
+
+    #[test]
+    fn global_id_prefix_mismatch() {
+        // base64("org:02v58c5a3fy30k560qrtg4rb2k")
+        Id::<User>::from_global_id("b3JnOjAydjU4YzVhM2Z5MzBrNTYwcXJ0ZzRyYjJr")
+            .expect_err("should have failed");
     }
 }
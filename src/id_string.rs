@@ -0,0 +1,181 @@
+use alloc::string::{String, ToString};
+use core::{
+    borrow::Borrow,
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    ops::Deref,
+    str::FromStr,
+};
+
+use crate::{Error, Id, Type};
+
+/// An `Id<T>` together with its `Display` form, rendered once when created.
+///
+/// `IdString<T>` dereferences to `str`, so it can be written to headers and logs without
+/// formatting the identifier again, and [`IdString::id`] gets the identifier back.
+/// Equality, ordering and hashing follow the string, so it can be looked up by `&str` in
+/// maps and sets.
+///
+/// The rendered form is the prefix, a separator and 26 characters, longer than the inline
+/// capacity of small-string types such as `CompactString` (24 bytes) or `SmolStr` (23
+/// bytes), so it can't be kept off the heap that way. To hold large numbers of identifiers
+/// in memory, store the 16-byte `Id<T>` and format it when needed.
+pub struct IdString<T: Type + ?Sized> {
+    id: Id<T>,
+    string: String,
+}
+
+impl<T: Type + ?Sized> IdString<T> {
+    /// Render the provided identifier.
+    pub fn new(id: Id<T>) -> Self {
+        Self {
+            id,
+            string: id.to_string(),
+        }
+    }
+
+    /// Get the identifier.
+    pub fn id(&self) -> Id<T> {
+        self.id
+    }
+
+    /// Get the rendered identifier.
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    /// Take the rendered identifier.
+    pub fn into_string(self) -> String {
+        self.string
+    }
+}
+
+impl<T: Type + ?Sized> Clone for IdString<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            string: self.string.clone(),
+        }
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq for IdString<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.string == other.string
+    }
+}
+
+impl<T: Type + ?Sized> Eq for IdString<T> {}
+
+impl<T: Type + ?Sized> PartialEq<str> for IdString<T> {
+    fn eq(&self, other: &str) -> bool {
+        self.string == other
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq<&str> for IdString<T> {
+    fn eq(&self, other: &&str) -> bool {
+        self.string == *other
+    }
+}
+
+impl<T: Type + ?Sized> PartialOrd for IdString<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Type + ?Sized> Ord for IdString<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.string.cmp(&other.string)
+    }
+}
+
+impl<T: Type + ?Sized> Hash for IdString<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.string.hash(state);
+    }
+}
+
+impl<T: Type + ?Sized> Deref for IdString<T> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.string
+    }
+}
+
+impl<T: Type + ?Sized> AsRef<str> for IdString<T> {
+    fn as_ref(&self) -> &str {
+        &self.string
+    }
+}
+
+impl<T: Type + ?Sized> Borrow<str> for IdString<T> {
+    fn borrow(&self) -> &str {
+        &self.string
+    }
+}
+
+impl<T: Type + ?Sized> Debug for IdString<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.string)
+    }
+}
+
+impl<T: Type + ?Sized> Display for IdString<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.string)
+    }
+}
+
+impl<T: Type + ?Sized> FromStr for IdString<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(Id::parse(s)?))
+    }
+}
+
+impl<T: Type + ?Sized> From<Id<T>> for IdString<T> {
+    fn from(value: Id<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Type + ?Sized> From<IdString<T>> for Id<T> {
+    fn from(value: IdString<T>) -> Self {
+        value.id
+    }
+}
+
+impl<T: Type + ?Sized> From<IdString<T>> for String {
+    fn from(value: IdString<T>) -> Self {
+        value.string
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, IdString, Type};
+    use std::collections::HashSet;
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn id_string_lookup() {
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+        let string = IdString::new(id);
+
+        assert_eq!(string.id(), id);
+        assert_eq!(string, "user_02v58c5a3fy30k560qrtg4rb2k");
+        assert!(string.starts_with("user_"));
+
+        let set = HashSet::from([string]);
+        assert!(set.contains("user_02v58c5a3fy30k560qrtg4rb2k"));
+    }
+}
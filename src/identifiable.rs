@@ -1,8 +1,65 @@
-use crate::Id;
+use crate::{Error, Id, IdBytes};
 
 /// A type that can be used in an identifier.
 pub trait Type {
     const PREFIX: &'static str;
+
+    /// The human-readable name of the type, such as `Workspace`, used in error messages
+    /// and schemas. Defaults to the prefix.
+    const NAME: &'static str = Self::PREFIX;
+
+    /// A description of what identifiers of this type refer to, used in schemas. Empty by
+    /// default.
+    const DESCRIPTION: &'static str = "";
+
+    /// Whether identifiers of this type are displayed with the value in uppercase, like
+    /// `lbl_02V58C5A3FY30K560QRTG4RB2K`, for printed labels and systems which normalize
+    /// to uppercase. `false` by default.
+    ///
+    /// The alternate flag, `{:#}`, displays the value in uppercase for any type, and
+    /// values in either case are accepted when parsing.
+    const UPPERCASE: bool = false;
+
+    /// Well-known values reserved for sentinel identifiers, such as a "system" or
+    /// "anonymous" user. Empty by default.
+    ///
+    /// Identifiers are never generated with a reserved value: `Id::generate` and
+    /// `Id::random` skip them, as do the arenas built on generators. Reserved values can
+    /// still be parsed, and are checked with `Id::is_reserved`. `Id::new` is `const`, so
+    /// sentinels can be declared as constants alongside the type:
+    ///
+    /// ```
+    /// use souvenir::{Id, IdBytes, Type};
+    ///
+    /// struct User;
+    ///
+    /// impl User {
+    ///     const SYSTEM: Id<User> = Id::new([0; 16]);
+    ///     const ANONYMOUS: Id<User> = Id::new(1u128.to_be_bytes());
+    /// }
+    ///
+    /// impl Type for User {
+    ///     const PREFIX: &'static str = "user";
+    ///     const RESERVED: &'static [IdBytes] = &[User::SYSTEM.to_bytes(), User::ANONYMOUS.to_bytes()];
+    /// }
+    ///
+    /// assert!(User::SYSTEM.is_reserved());
+    /// ```
+    const RESERVED: &'static [IdBytes] = &[];
+
+    /// Check that an identifier value is valid for this type, such as being outside of
+    /// a reserved range.
+    ///
+    /// This is called by `Id::parse` and the other fallible string parsers, such as
+    /// `Id::parse_foreign`, by `TryFrom<&[u8]>`, and by `Id::new_checked`. `Id::generate`,
+    /// `Id::generate_async` and random generation skip values it rejects, so a type must
+    /// accept a reasonable share of values. Accepts every value by default. Values created
+    /// with `Id::new` and the other infallible constructors, such as `From<Uuid>`, or
+    /// decoded by binary formats are not checked.
+    fn validate(bytes: &IdBytes) -> Result<(), Error> {
+        let _ = bytes;
+        Ok(())
+    }
 }
 
 /// A type which can by identified with an `Id<Self>`.
@@ -0,0 +1,322 @@
+use crate::{encoding::parse_base32, Error, Id, Type};
+
+/// Seconds between the Unix epoch and the KSUID epoch.
+const KSUID_EPOCH: u64 = 1_400_000_000;
+
+/// Milliseconds between the Unix epoch and the Discord snowflake epoch, the start of 2015.
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Milliseconds between the Unix epoch and the Twitter snowflake epoch.
+pub const TWITTER_EPOCH: u64 = 1_288_834_974_657;
+
+/// Parse a UUID in its hyphenated or simple hexadecimal form.
+#[cfg(feature = "uuid")]
+fn parse_uuid(value: &str) -> Option<[u8; 16]> {
+    ::uuid::Uuid::try_parse(value)
+        .ok()
+        .map(::uuid::Uuid::into_bytes)
+}
+
+/// Parse a UUID in its hyphenated or simple hexadecimal form.
+#[cfg(not(feature = "uuid"))]
+fn parse_uuid(value: &str) -> Option<[u8; 16]> {
+    let mut bytes = [0; 16];
+
+    // Skip the hyphens between groups, leaving any others to fail as hexadecimal digits.
+    let mut digits = value
+        .bytes()
+        .enumerate()
+        .filter(|&(i, b)| value.len() != 36 || !matches!(i, 8 | 13 | 18 | 23) || b != b'-')
+        .map(|(_, b)| b);
+
+    for byte in &mut bytes {
+        for _ in 0..2 {
+            let digit = digits.next()?;
+            *byte = *byte << 4 | (digit as char).to_digit(16)? as u8;
+        }
+    }
+
+    digits.next().is_none().then_some(bytes)
+}
+
+/// Parse a ULID in either case.
+fn parse_ulid(value: &str) -> Option<[u8; 16]> {
+    let mut buf: [u8; 26] = value.as_bytes().try_into().ok()?;
+    buf.make_ascii_lowercase();
+
+    parse_base32(core::str::from_utf8(&buf).ok()?).ok()
+}
+
+/// Parse a KSUID from its 27 character base62 form.
+fn parse_ksuid(value: &str) -> Option<[u8; 20]> {
+    if value.len() != 27 {
+        return None;
+    }
+
+    // Big-endian 32-bit limbs of the 160-bit value.
+    let mut limbs = [0u32; 5];
+
+    for b in value.bytes() {
+        let mut carry = match b {
+            b'0'..=b'9' => b - b'0',
+            b'A'..=b'Z' => b - b'A' + 10,
+            b'a'..=b'z' => b - b'a' + 36,
+            _ => return None,
+        } as u64;
+
+        for limb in limbs.iter_mut().rev() {
+            let value = *limb as u64 * 62 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+
+        if carry != 0 {
+            return None;
+        }
+    }
+
+    let mut ksuid = [0; 20];
+
+    for (bytes, limb) in ksuid.chunks_exact_mut(4).zip(limbs) {
+        bytes.copy_from_slice(&limb.to_be_bytes());
+    }
+
+    Some(ksuid)
+}
+
+/// Parse a 21 character nanoid with the default alphabet as base64url digits.
+fn parse_nanoid(value: &str) -> Option<u128> {
+    if value.len() != 21 {
+        return None;
+    }
+
+    let mut out = 0u128;
+
+    for b in value.bytes() {
+        let digit = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'-' => 62,
+            b'_' => 63,
+            _ => return None,
+        };
+
+        out = out << 6 | digit as u128;
+    }
+
+    Some(out)
+}
+
+/// Parse a cuid2 as base36 digits, reduced modulo 2^128.
+fn parse_cuid2(value: &str) -> Option<u128> {
+    if !(2..=32).contains(&value.len()) || !value.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return None;
+    }
+
+    let mut out = 0u128;
+
+    for b in value.bytes() {
+        let digit = match b {
+            b'0'..=b'9' => b - b'0',
+            b'a'..=b'z' => b - b'a' + 10,
+            _ => return None,
+        };
+
+        out = out.wrapping_mul(36).wrapping_add(digit as u128);
+    }
+
+    Some(out)
+}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Map the 20 bytes of a KSUID to a time-ordered `Id<T>`.
+    ///
+    /// The KSUID timestamp, in seconds since its epoch of `1400000000`, is kept as whole
+    /// milliseconds since the Unix epoch in the top 48 bits. The 128-bit payload is folded
+    /// into the remaining 80 bits by XOR-ing its top 48 bits into its bottom 80 bits. The
+    /// mapping is deterministic, so importing the same KSUID always gives the same
+    /// identifier, and identifiers keep the order of their KSUIDs' timestamps. The result
+    /// is not checked with [`Type::validate`].
+    pub fn from_ksuid(ksuid: [u8; 20]) -> Self {
+        let (timestamp, payload) = ksuid.split_at(4);
+        let seconds = u32::from_be_bytes(timestamp.try_into().unwrap()) as u64;
+        let payload = u128::from_be_bytes(payload.try_into().unwrap());
+
+        let millis = ((seconds + KSUID_EPOCH) * 1000) as u128;
+        let folded = (payload ^ payload >> 80) & (u128::MAX >> 48);
+
+        Self::from(millis << 80 | folded)
+    }
+
+    /// Parse a KSUID from its 27 character base62 form and map it to an `Id<T>`, checking
+    /// the result with [`Type::validate`].
+    ///
+    /// See [`Id::from_ksuid`] for the mapping.
+    pub fn parse_ksuid(value: &str) -> Result<Self, Error> {
+        let ksuid = parse_ksuid(value).ok_or(Error::InvalidData)?;
+
+        Self::new_checked(Self::from_ksuid(ksuid).to_bytes())
+    }
+
+    /// Map a 21 character nanoid with the default alphabet to an `Id<T>`, checking the
+    /// result with [`Type::validate`].
+    ///
+    /// The characters are read as base64url digits (`A-Z`, `a-z`, `0-9`, `-`, `_`), giving
+    /// a 126-bit value which is stored unchanged in the low bits. The mapping is lossless,
+    /// so every nanoid maps to a distinct identifier and can be recovered from it.
+    pub fn from_nanoid(value: &str) -> Result<Self, Error> {
+        let value = parse_nanoid(value).ok_or(Error::InvalidData)?;
+
+        Self::new_checked(value.to_be_bytes())
+    }
+
+    /// Map a cuid2 to an `Id<T>`, checking the result with [`Type::validate`].
+    ///
+    /// cuid2s are 2 to 32 lowercase ASCII letters and digits, starting with a letter. The
+    /// characters are read as base36 digits and the value is stored in the low bits. This
+    /// is lossless for cuid2s of up to 24 characters, the default length, so they map to
+    /// distinct identifiers. Longer cuid2s are reduced modulo 2^128, which keeps
+    /// the mapping deterministic but may map two cuid2s to the same identifier.
+    pub fn from_cuid2(value: &str) -> Result<Self, Error> {
+        let value = parse_cuid2(value).ok_or(Error::InvalidData)?;
+
+        Self::new_checked(value.to_be_bytes())
+    }
+
+    /// Map a snowflake to a time-ordered `Id<T>`.
+    ///
+    /// Snowflakes hold the milliseconds since their service's epoch above their low 22
+    /// bits. `epoch` is that epoch in milliseconds since the Unix epoch, such as
+    /// [`DISCORD_EPOCH`] or [`TWITTER_EPOCH`]. The timestamp is converted to milliseconds
+    /// since the Unix epoch and stored in the top 48 bits, so it is kept for
+    /// `Id::created_at`, and the whole snowflake is stored in the low 64 bits, so the
+    /// mapping is lossless and identifiers sort like their snowflakes. The result is not
+    /// checked with [`Type::validate`].
+    pub fn from_snowflake(snowflake: u64, epoch: u64) -> Self {
+        let millis = ((snowflake >> 22) + epoch) as u128;
+
+        Self::from(millis << 80 | snowflake as u128)
+    }
+
+    /// Attempt to parse an identifier which may come from another system, recognizing
+    /// the format by its shape.
+    ///
+    /// The following formats are recognized, and anything else is parsed as an `Id<T>`:
+    ///
+    /// - UUIDs, hyphenated or as 32 hexadecimal digits, keeping their bytes unchanged.
+    /// - ULIDs, in either case, keeping their value unchanged.
+    /// - KSUIDs, mapped with [`Id::from_ksuid`].
+    /// - nanoids with the default alphabet and length, mapped with [`Id::from_nanoid`].
+    /// - cuid2s with the default length, mapped with [`Id::from_cuid2`].
+    ///
+    /// Recognized values are checked with [`Type::validate`], like parsed identifiers.
+    pub fn parse_foreign(value: &str) -> Result<Self, Error> {
+        let foreign = match value.len() {
+            21 => parse_nanoid(value).map(u128::to_be_bytes),
+            24 => parse_cuid2(value).map(u128::to_be_bytes),
+            26 => parse_ulid(value),
+            27 => parse_ksuid(value).map(|ksuid| Self::from_ksuid(ksuid).to_bytes()),
+            32 | 36 => parse_uuid(value),
+            _ => None,
+        };
+
+        match foreign {
+            Some(value) => Self::new_checked(value),
+            None => Self::parse(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Error, Id, IdBytes, Type, DISCORD_EPOCH};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn ksuid_timestamp() {
+        let id = Id::<User>::parse_ksuid("0ujtsYcgvSTl8PAuAdqWYSMnLOv").unwrap();
+
+        // The example KSUID from the specification, created at 2017-10-10T04:00:47Z.
+        assert_eq!(id.to_u128() >> 80, 1_507_608_047_000);
+
+        assert!(Id::<User>::parse_ksuid("zzzzzzzzzzzzzzzzzzzzzzzzzzz").is_err());
+        assert!(Id::<User>::parse_ksuid("0ujtsYcgvSTl8PAuAdqWYSMnLO_").is_err());
+    }
+
+    #[test]
+    fn snowflake_timestamp() {
+        let id = Id::<User>::from_snowflake(175928847299117063, DISCORD_EPOCH);
+
+        // The example snowflake from the Discord documentation, created at
+        // 2016-04-30T11:18:25.796Z.
+        assert_eq!(id.to_u128() >> 80, 1_462_015_105_796);
+        assert_eq!(id.to_u128() as u64, 175928847299117063);
+    }
+
+    #[test]
+    fn foreign_formats() {
+        let parse = |value| Id::<User>::parse_foreign(value).map(Id::to_u128);
+
+        assert_eq!(parse("AAAAAAAAAAAAAAAAAAAA_").unwrap(), 63);
+        assert_eq!(
+            parse("tz4a98xxat96iws9zmbrgj3a").unwrap(),
+            u128::from_str_radix("tz4a98xxat96iws9zmbrgj3a", 36).unwrap()
+        );
+        assert_eq!(
+            parse("02d950c2-a86f-f0c1-3298-17c6a04c2c53").unwrap(),
+            0x02d950c2_a86f_f0c1_3298_17c6a04c2c53
+        );
+        assert_eq!(
+            parse("02D950C2A86FF0C1329817C6A04C2C53").unwrap(),
+            0x02d950c2_a86f_f0c1_3298_17c6a04c2c53
+        );
+        assert_eq!(
+            parse("02V58C5A3FY30K560QRTG4RB2K").unwrap(),
+            0x02d950c2_a86f_f0c1_3298_17c6a04c2c53
+        );
+        assert_eq!(
+            parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap(),
+            0x02d950c2_a86f_f0c1_3298_17c6a04c2c53
+        );
+
+        assert!(parse("02d950c2-a86f-f0c1-3298+17c6a04c2c53").is_err());
+        assert!(parse("org_02v58c5a3fy30k560qrtg4rb2k").is_err());
+    }
+
+    #[test]
+    fn foreign_validated() {
+        /// Accounts below 1000 are kept for internal use.
+        struct Account;
+
+        impl Type for Account {
+            const PREFIX: &'static str = "acct";
+
+            fn validate(bytes: &IdBytes) -> Result<(), Error> {
+                match u128::from_be_bytes(*bytes) {
+                    0..1000 => Err(Error::InvalidData),
+                    _ => Ok(()),
+                }
+            }
+        }
+
+        for value in [
+            "AAAAAAAAAAAAAAAAAAAA_",
+            "00000000000000000000000001",
+            "00000000-0000-0000-0000-000000000001",
+        ] {
+            assert!(Id::<Account>::parse_foreign(value).is_err());
+        }
+
+        assert!(Id::<Account>::from_nanoid("AAAAAAAAAAAAAAAAAAAA_").is_err());
+        assert!(Id::<Account>::from_nanoid("AAAAAAAAAAAAAAAAAAAQA").is_ok());
+        assert!(Id::<Account>::from_cuid2("aa").is_err());
+        assert!(Id::<Account>::from_cuid2("zz").is_ok());
+        assert!(Id::<Account>::parse_ksuid("0ujtsYcgvSTl8PAuAdqWYSMnLOv").is_ok());
+    }
+}
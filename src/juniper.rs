@@ -0,0 +1,9 @@
+use crate::{Id, Type};
+
+pub(crate) fn to_output<T: Type + ?Sized>(value: &Id<T>) -> String {
+    value.to_string()
+}
+
+pub(crate) fn from_input<T: Type + ?Sized>(value: &str) -> Result<Id<T>, Box<str>> {
+    Id::parse(value).map_err(|e| format!("invalid `{}` identifier: {}", T::PREFIX, e).into())
+}
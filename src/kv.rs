@@ -0,0 +1,36 @@
+use crate::{Id, Type};
+use log::kv::{ToValue, Value};
+
+// Captured through `Display`, so backends receive the formatted identifier as a single
+// field, e.g. `log::info!(user = id; "signed in")`, without it being formatted up front.
+impl<T: Type + ?Sized> ToValue for Id<T> {
+    fn to_value(&self) -> Value<'_> {
+        Value::from_display(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToValue for crate::Generic {
+    fn to_value(&self) -> Value<'_> {
+        Value::from_display(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type};
+    use log::kv::ToValue;
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn kv_value() {
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+
+        assert_eq!(id.to_value().to_string(), "user_02v58c5a3fy30k560qrtg4rb2k");
+    }
+}
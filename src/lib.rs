@@ -1,22 +1,225 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "uniffi")]
+::uniffi::setup_scaffolding!();
+
+#[cfg(feature = "actix")]
+pub mod actix;
+
+#[cfg(feature = "aes")]
+mod aes;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "async-graphql")]
+mod async_graphql;
+
+#[cfg(feature = "axum")]
+pub mod axum;
+
+#[cfg(feature = "bincode")]
+mod bincode;
+
+#[cfg(feature = "bip39")]
+mod bip39;
+
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "clap")]
+pub mod clap;
+
+#[cfg(feature = "defmt")]
+mod defmt;
+
 #[cfg(feature = "diesel")]
 mod diesel;
 
+#[cfg(feature = "speedy")]
+mod speedy;
+
 #[cfg(feature = "sqlx")]
 mod sqlx;
 
+#[cfg(feature = "fake")]
+mod fake;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "hmac")]
+pub mod hmac;
+
+#[cfg(feature = "juniper")]
+mod juniper;
+
+#[cfg(feature = "kv")]
+mod kv;
+
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
+#[cfg(feature = "minicbor")]
+mod minicbor;
+
+#[cfg(feature = "napi")]
+pub mod napi;
+
+#[cfg(feature = "opentelemetry")]
+mod opentelemetry;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "prost")]
+pub mod prost;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+
 #[cfg(feature = "rand")]
 mod rand;
 
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
+#[cfg(feature = "roaring")]
+mod roaring;
+
+#[cfg(feature = "rocket")]
+mod rocket;
+
+#[cfg(feature = "schemars")]
+mod schemars;
+
 #[cfg(feature = "serde")]
 mod serde;
 
+#[cfg(feature = "subtle")]
+mod subtle;
+
+#[cfg(feature = "tower-http")]
+pub mod tower_http;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "ts-rs")]
+mod ts_rs;
+
+#[cfg(feature = "ulid")]
+mod ulid;
+
+#[cfg(feature = "uniffi")]
+pub mod uniffi;
+
+#[cfg(feature = "utoipa")]
+mod utoipa;
+
+#[cfg(feature = "uuid")]
+mod uuid;
+
+#[cfg(feature = "validator")]
+pub mod validator;
+
+#[cfg(feature = "valuable")]
+mod valuable;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+#[cfg(all(feature = "std", target_has_atomic = "64"))]
+mod arena;
+#[cfg(feature = "std")]
+mod async_generator;
+#[cfg(feature = "alloc")]
+pub mod codec;
+#[cfg(feature = "std")]
+mod collections;
+mod color;
+mod composite;
+#[cfg(feature = "std")]
+mod config;
+#[cfg(feature = "alloc")]
+mod cursor;
 mod encoding;
+#[cfg(feature = "std")]
+mod environment;
 mod error;
+mod etag;
+#[cfg(feature = "std")]
+mod explain;
+mod fingerprint;
+#[cfg(feature = "alloc")]
+mod foreign;
+mod generator;
+#[cfg(feature = "alloc")]
 mod generic;
+mod hash;
+#[cfg(feature = "std")]
+mod hooks;
 mod id;
+#[cfg(feature = "alloc")]
+mod id_string;
 mod identifiable;
+mod import;
+#[cfg(feature = "std")]
+pub mod migrate;
+mod radix;
+mod region;
+mod scoped;
+mod secret;
+mod slug;
+mod spell;
+#[cfg(feature = "std")]
+mod time;
+mod typeid;
+mod versioned;
 
+#[cfg(all(feature = "std", target_has_atomic = "64"))]
+pub use arena::*;
+#[cfg(feature = "std")]
+pub use async_generator::*;
+#[cfg(feature = "std")]
+pub use collections::*;
+pub use color::*;
+pub use composite::*;
+#[cfg(feature = "std")]
+pub use config::*;
+#[cfg(feature = "alloc")]
+pub use cursor::*;
+#[cfg(feature = "std")]
+pub use environment::{environment, set_environment};
 pub use error::*;
+pub use etag::*;
+#[cfg(feature = "std")]
+pub use explain::*;
+pub use fingerprint::*;
+#[cfg(feature = "alloc")]
+pub use foreign::*;
+pub use generator::*;
+#[cfg(feature = "alloc")]
 pub use generic::*;
+#[cfg(feature = "std")]
+pub use hooks::*;
 pub use id::*;
+#[cfg(feature = "alloc")]
+pub use id_string::*;
 pub use identifiable::*;
+pub use import::*;
+pub use region::*;
+pub use scoped::*;
+pub use secret::*;
+pub use slug::*;
+pub use spell::*;
+pub use versioned::*;
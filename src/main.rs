@@ -0,0 +1,258 @@
+//! Command line tool for generating and inspecting identifiers.
+//!
+//! ```text
+//! souvenir new user
+//! souvenir new user --count 100000 --format csv --generator timestamped
+//! souvenir new user --generator multi-region --region 3 --worker 12
+//! souvenir inspect user_02v58c5a3fy30k560qrtg4rb2k
+//! souvenir check user_02v58c5a3fy30k560qrtg4rb2k --prefix user
+//! souvenir from-uuid user 02d950c2-a86f-f0c1-3298-17c6a04c2c53
+//! souvenir to-uuid user_02v58c5a3fy30k560qrtg4rb2k
+//! souvenir vectors > vectors.json
+//! ```
+
+use clap::{Parser, Subcommand, ValueEnum};
+use souvenir::{Generator, Generic, Layout, MultiRegion, Random, Sequential, Timestamped};
+use std::io::{self, BufWriter, ErrorKind, Write};
+use std::process::ExitCode;
+
+/// Generate and inspect prefixed identifiers.
+#[derive(Parser)]
+#[command(name = "souvenir", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate new identifiers with the provided prefix.
+    New {
+        prefix: String,
+        /// Number of identifiers to generate.
+        #[arg(long, default_value_t = 1)]
+        count: u64,
+        /// Output format.
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Source of identifier values.
+        #[arg(long, value_enum, default_value_t = Source::Random)]
+        generator: Source,
+        /// Region code for the multi-region generator.
+        #[arg(long, default_value_t = 0)]
+        region: u8,
+        /// Worker number within the region for the multi-region generator.
+        #[arg(long, default_value_t = 0)]
+        worker: u16,
+    },
+    /// Show the prefix, bytes and numeric value of an identifier.
+    ///
+    /// Without a layout, the embedded timestamp is also shown when the top 48 bits hold a
+    /// plausible millisecond time (between 2000 and now), as in time-ordered identifiers.
+    Inspect {
+        id: String,
+        /// Generator which produced the identifier, to decode its fields.
+        #[arg(long, value_enum)]
+        layout: Option<Kind>,
+    },
+    /// Check that an identifier is valid, exiting with a failure status if not.
+    Check {
+        id: String,
+        /// Require the identifier to have this prefix.
+        #[arg(long)]
+        prefix: Option<String>,
+    },
+    /// Convert a UUID into an identifier with the provided prefix.
+    ///
+    /// The identifier holds the 16 bytes of the UUID unchanged, so the conversion is
+    /// lossless in both directions.
+    FromUuid { prefix: String, uuid: String },
+    /// Convert an identifier into a UUID with the same 16 bytes.
+    ToUuid { id: String },
+    /// Print canonical test vectors as JSON, for checking other implementations.
+    Vectors,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One identifier per line.
+    Text,
+    /// A CSV table with an `id` column.
+    Csv,
+    /// A JSON array of `{"id": ...}` objects.
+    Json,
+    /// One `{"id": ...}` JSON object per line.
+    Ndjson,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Source {
+    /// Random 128-bit values.
+    Random,
+    /// Millisecond timestamp followed by random bits, sorting by creation time.
+    Timestamped,
+    /// Consecutive values starting at 1.
+    Sequential,
+    /// Timestamp, region, worker, sequence and random bits, set with `--region` and
+    /// `--worker`.
+    MultiRegion,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Kind {
+    /// Random 128-bit values.
+    Random,
+    /// Millisecond timestamp followed by random bits.
+    Timestamped,
+    /// Consecutive values.
+    Sequential,
+    /// Timestamp, region, worker, sequence and random bits.
+    MultiRegion,
+}
+
+impl From<Kind> for Layout {
+    fn from(value: Kind) -> Self {
+        match value {
+            Kind::Random => Layout::Random,
+            Kind::Timestamped => Layout::Timestamped,
+            Kind::Sequential => Layout::Sequential,
+            Kind::MultiRegion => Layout::MultiRegion,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::New {
+            prefix,
+            count,
+            format,
+            generator,
+            region,
+            worker,
+        } => {
+            if let Err(error) = Generic::new(prefix.as_str(), [0; 16]) {
+                eprintln!("invalid: {}", error);
+                return ExitCode::FAILURE;
+            }
+
+            let generator: &dyn Generator = match generator {
+                Source::Random => &Random,
+                Source::Timestamped => &Timestamped,
+                Source::Sequential => &Sequential::new(),
+                Source::MultiRegion => &MultiRegion::new(region, worker),
+            };
+
+            match generate(&prefix, count, format, generator) {
+                Err(error) if error.kind() != ErrorKind::BrokenPipe => {
+                    eprintln!("error: {}", error);
+                    return ExitCode::FAILURE;
+                }
+                _ => {}
+            }
+        }
+        Command::Inspect { id, layout } => {
+            let Some(id) = parse(&id, None) else {
+                return ExitCode::FAILURE;
+            };
+
+            println!(
+                "{}",
+                id.describe(layout.map_or(Layout::Unknown, Layout::from))
+            );
+        }
+        Command::Check { id, prefix } => {
+            if parse(&id, prefix.as_deref()).is_none() {
+                return ExitCode::FAILURE;
+            }
+
+            println!("valid");
+        }
+        Command::FromUuid { prefix, uuid } => {
+            let Ok(value) = uuid::Uuid::try_parse(&uuid) else {
+                eprintln!("invalid: expected a UUID, found `{}`", uuid);
+                return ExitCode::FAILURE;
+            };
+
+            match Generic::new(prefix, value.into_bytes()) {
+                Ok(id) => println!("{}", id),
+                Err(error) => {
+                    eprintln!("invalid: {}", error);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::ToUuid { id } => {
+            let Some(id) = parse(&id, None) else {
+                return ExitCode::FAILURE;
+            };
+
+            let hex = hex(id.as_bytes());
+            println!(
+                "{}-{}-{}-{}-{}",
+                &hex[..8],
+                &hex[8..12],
+                &hex[12..16],
+                &hex[16..20],
+                &hex[20..]
+            );
+        }
+        Command::Vectors => print!("{}", souvenir::testing::test_vectors()),
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Write `count` identifiers to standard output as they are generated.
+fn generate(prefix: &str, count: u64, format: Format, generator: &dyn Generator) -> io::Result<()> {
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    match format {
+        Format::Csv => writeln!(out, "id")?,
+        Format::Json => write!(out, "[")?,
+        Format::Text | Format::Ndjson => {}
+    }
+
+    for i in 0..count {
+        let id = Generic::new(prefix, generator.next_value()).expect("prefix to be checked");
+
+        // Identifiers only contain ASCII alphanumerics and underscores, so they never need
+        // quoting or escaping.
+        match format {
+            Format::Text | Format::Csv => writeln!(out, "{}", id)?,
+            Format::Json if i == 0 => write!(out, "\n  {{\"id\": \"{}\"}}", id)?,
+            Format::Json => write!(out, ",\n  {{\"id\": \"{}\"}}", id)?,
+            Format::Ndjson => writeln!(out, "{{\"id\": \"{}\"}}", id)?,
+        }
+    }
+
+    if let Format::Json = format {
+        writeln!(out, "{}]", if count == 0 { "" } else { "\n" })?;
+    }
+
+    out.flush()
+}
+
+/// Parse an identifier, reporting why it is invalid on standard error.
+fn parse(value: &str, prefix: Option<&str>) -> Option<Generic> {
+    match Generic::parse(value) {
+        Ok(id) if prefix.is_none_or(|prefix| prefix == id.prefix()) => Some(id),
+        Ok(id) => {
+            eprintln!(
+                "invalid: expected prefix `{}`, found `{}`",
+                prefix.unwrap_or_default(),
+                id.prefix()
+            );
+            None
+        }
+        Err(error) => {
+            eprintln!("invalid: {}", error);
+            None
+        }
+    }
+}
+
+/// Format bytes as lowercase hexadecimal.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
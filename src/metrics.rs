@@ -0,0 +1,43 @@
+//! Metrics for identifier generation and parsing.
+//!
+//! With the `metrics` feature, the following counters are recorded through the
+//! [`metrics`](https://docs.rs/metrics) facade, for whichever recorder the application
+//! installs:
+//!
+//! | Name                                 | Labels            | Counts                          |
+//! |--------------------------------------|-------------------|---------------------------------|
+//! | `souvenir_ids_generated_total`       | `type`            | identifiers generated           |
+//! | `souvenir_parse_failures_total`      | `type`, `error`   | failures of `Id::parse`         |
+//! | `souvenir_sequence_exhausted_total`  | `generator`       | generators running out of space |
+//!
+//! `type` is the prefix of the identifier type, and `error` the name of the [`Error`]
+//! variant, such as `PrefixMismatch`. `MultiRegion` generators count an exhaustion each
+//! time they borrow the next millisecond, and `Sequential` generators count one before
+//! panicking.
+
+use crate::Error;
+use ::metrics::counter;
+
+/// Record a generated identifier.
+pub(crate) fn generated(prefix: &'static str) {
+    counter!("souvenir_ids_generated_total", "type" => prefix).increment(1);
+}
+
+/// Record a failure to parse an identifier.
+pub(crate) fn parse_failed(prefix: &'static str, error: &Error) {
+    let error = match error {
+        Error::InvalidData => "InvalidData",
+        Error::InvalidPrefix => "InvalidPrefix",
+        Error::PrefixMismatch { .. } => "PrefixMismatch",
+        Error::EnvironmentMismatch { .. } => "EnvironmentMismatch",
+        Error::InvalidSignature => "InvalidSignature",
+        Error::Expired => "Expired",
+    };
+
+    counter!("souvenir_parse_failures_total", "type" => prefix, "error" => error).increment(1);
+}
+
+/// Record a generator running out of values.
+pub(crate) fn exhausted(generator: &'static str) {
+    counter!("souvenir_sequence_exhausted_total", "generator" => generator).increment(1);
+}
@@ -0,0 +1,324 @@
+//! Re-keying legacy records with souvenir identifiers.
+//!
+//! A [`KeyMapper`] turns the keys of an existing table, whether integer serials, UUIDs or
+//! arbitrary strings, into identifiers. The mapping is deterministic, so a backfill job
+//! can be stopped, restarted or sharded across machines and every run agrees on the
+//! identifier of each record without coordinating:
+//!
+//! ```
+//! use souvenir::migrate::KeyMapper;
+//! use souvenir::{Id, Type};
+//!
+//! struct User;
+//!
+//! impl Type for User {
+//!     const PREFIX: &'static str = "user";
+//! }
+//!
+//! let mut mapper = KeyMapper::<User>::new("users");
+//!
+//! let id = mapper.map(42).unwrap();
+//! assert_eq!(mapper.map(42).unwrap(), id);
+//! assert_eq!(mapper.id_for(42), id);
+//! assert_ne!(mapper.map("legacy-key").unwrap(), id);
+//! ```
+//!
+//! Serials and UUIDs map without loss, so distinct keys of those kinds never collide.
+//! Strings are hashed with the 128-bit FNV-1a hash, which makes collisions vanishingly
+//! unlikely but not impossible; [`KeyMapper::map`] records every key it maps in a
+//! [`MappingStore`] and fails with [`MigrateError::Collision`] rather than hand out the
+//! same identifier twice. The default [`MemoryStore`] keeps the table in memory, and
+//! other stores can persist it, for example in the database being migrated, so that
+//! collisions are caught across runs.
+use std::convert::Infallible;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::{Id, IdMap, Type};
+
+/// A key from a legacy table.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LegacyKey<'a> {
+    /// An integer serial, such as a `BIGSERIAL` primary key.
+    Serial(i64),
+    /// The 16 bytes of a UUID.
+    Uuid([u8; 16]),
+    /// An arbitrary string key.
+    String(&'a str),
+}
+
+impl LegacyKey<'_> {
+    /// Encode this key as bytes, for storage in a [`MappingStore`].
+    ///
+    /// The encoding is a tag byte, `s`, `u` or `t`, followed by the big-endian serial, the
+    /// UUID bytes or the UTF-8 string, so keys of different kinds never encode the same.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let serial;
+        let (tag, data): (u8, &[u8]) = match self {
+            LegacyKey::Serial(value) => {
+                serial = value.to_be_bytes();
+                (b's', &serial)
+            }
+            LegacyKey::Uuid(value) => (b'u', value),
+            LegacyKey::String(value) => (b't', value.as_bytes()),
+        };
+
+        [&[tag][..], data].concat()
+    }
+}
+
+impl From<i64> for LegacyKey<'_> {
+    fn from(value: i64) -> Self {
+        LegacyKey::Serial(value)
+    }
+}
+
+impl From<i32> for LegacyKey<'_> {
+    fn from(value: i32) -> Self {
+        LegacyKey::Serial(value.into())
+    }
+}
+
+impl From<[u8; 16]> for LegacyKey<'_> {
+    fn from(value: [u8; 16]) -> Self {
+        LegacyKey::Uuid(value)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<::uuid::Uuid> for LegacyKey<'_> {
+    fn from(value: ::uuid::Uuid) -> Self {
+        LegacyKey::Uuid(value.into_bytes())
+    }
+}
+
+impl<'a> From<&'a str> for LegacyKey<'a> {
+    fn from(value: &'a str) -> Self {
+        LegacyKey::String(value)
+    }
+}
+
+impl<'a> From<&'a String> for LegacyKey<'a> {
+    fn from(value: &'a String) -> Self {
+        LegacyKey::String(value)
+    }
+}
+
+/// A table recording which legacy key each identifier was mapped from.
+pub trait MappingStore<T: Type + ?Sized> {
+    /// The error returned when the table can't be read or written.
+    type Error;
+
+    /// Get the encoded legacy key recorded for an identifier, if any.
+    fn get(&self, id: Id<T>) -> Result<Option<Vec<u8>>, Self::Error>;
+
+    /// Record the encoded legacy key an identifier was mapped from.
+    fn insert(&mut self, id: Id<T>, key: Vec<u8>) -> Result<(), Self::Error>;
+}
+
+/// A [`MappingStore`] held in memory.
+pub struct MemoryStore<T: Type + ?Sized> {
+    keys: IdMap<T, Vec<u8>>,
+}
+
+impl<T: Type + ?Sized> MemoryStore<T> {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self { keys: IdMap::new() }
+    }
+
+    /// Get the number of recorded mappings.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Check if no mappings have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl<T: Type + ?Sized> Default for MemoryStore<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Type + ?Sized> Debug for MemoryStore<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemoryStore")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<T: Type + ?Sized> MappingStore<T> for MemoryStore<T> {
+    type Error = Infallible;
+
+    fn get(&self, id: Id<T>) -> Result<Option<Vec<u8>>, Self::Error> {
+        Ok(self.keys.get(&id).cloned())
+    }
+
+    fn insert(&mut self, id: Id<T>, key: Vec<u8>) -> Result<(), Self::Error> {
+        self.keys.insert(id, key);
+        Ok(())
+    }
+}
+
+/// An error mapping a legacy key.
+#[derive(Debug)]
+pub enum MigrateError<E> {
+    /// The identifier was already mapped from a different legacy key, whose encoding is
+    /// included.
+    Collision { existing: Vec<u8> },
+    /// The mapping store failed.
+    Store(E),
+}
+
+impl<E: Display> Display for MigrateError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MigrateError::Collision { .. } => {
+                write!(f, "identifier already mapped from a different key")
+            }
+            MigrateError::Store(e) => write!(f, "mapping store error: {}", e),
+        }
+    }
+}
+
+impl<E: Debug + Display> std::error::Error for MigrateError<E> {}
+
+/// Deterministically maps legacy keys to identifiers.
+///
+/// Keys are mapped within a namespace, usually the name of the legacy table, so that the
+/// same serial in two tables gets two identifiers. UUID keys are used as the identifier
+/// value unchanged, regardless of the namespace.
+pub struct KeyMapper<T: Type + ?Sized, S: MappingStore<T> = MemoryStore<T>> {
+    marker: PhantomData<fn() -> T>,
+    namespace: u128,
+    store: S,
+}
+
+impl<T: Type + ?Sized> KeyMapper<T> {
+    /// Create a mapper for the provided namespace, recording mappings in memory.
+    pub fn new(namespace: &str) -> Self {
+        Self::with_store(namespace, MemoryStore::new())
+    }
+}
+
+impl<T: Type + ?Sized, S: MappingStore<T>> KeyMapper<T, S> {
+    /// Create a mapper for the provided namespace, recording mappings in `store`.
+    pub fn with_store(namespace: &str, store: S) -> Self {
+        Self {
+            marker: PhantomData,
+            namespace: fnv1a(FNV_OFFSET, namespace.as_bytes()),
+            store,
+        }
+    }
+
+    /// Get the identifier for a legacy key, without recording or checking the mapping, or
+    /// checking the identifier with [`Type::validate`].
+    ///
+    /// Serials are stored in the low 64 bits, under the top 64 bits of the namespace hash.
+    /// Strings are hashed together with the namespace.
+    pub fn id_for<'a>(&self, key: impl Into<LegacyKey<'a>>) -> Id<T> {
+        let value = match key.into() {
+            LegacyKey::Serial(value) => self.namespace & !(u64::MAX as u128) | value as u64 as u128,
+            LegacyKey::Uuid(value) => u128::from_be_bytes(value),
+            LegacyKey::String(value) => fnv1a(self.namespace, value.as_bytes()),
+        };
+
+        Id::new(value.to_be_bytes())
+    }
+
+    /// Map a legacy key to its identifier, recording the mapping in the store.
+    ///
+    /// Mapping a key again returns the same identifier. Fails if the identifier was
+    /// already recorded for a different key.
+    pub fn map<'a>(
+        &mut self,
+        key: impl Into<LegacyKey<'a>>,
+    ) -> Result<Id<T>, MigrateError<S::Error>> {
+        let key = key.into();
+        let id = self.id_for(key);
+        let bytes = key.to_bytes();
+
+        match self.store.get(id).map_err(MigrateError::Store)? {
+            Some(existing) if existing != bytes => Err(MigrateError::Collision { existing }),
+            Some(_) => Ok(id),
+            None => {
+                self.store.insert(id, bytes).map_err(MigrateError::Store)?;
+                Ok(id)
+            }
+        }
+    }
+
+    /// Get a reference to the mapping store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Take the mapping store out of this mapper.
+    pub fn into_store(self) -> S {
+        self.store
+    }
+}
+
+impl<T: Type + ?Sized, S: MappingStore<T> + Debug> Debug for KeyMapper<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyMapper")
+            .field("namespace", &self.namespace)
+            .field("store", &self.store)
+            .finish()
+    }
+}
+
+const FNV_OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+const FNV_PRIME: u128 = 0x0000000001000000000000000000013b;
+
+/// Continue a 128-bit FNV-1a hash from `state` over `data`.
+fn fnv1a(state: u128, data: &[u8]) -> u128 {
+    data.iter()
+        .fold(state, |h, b| (h ^ *b as u128).wrapping_mul(FNV_PRIME))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{KeyMapper, LegacyKey, MappingStore, MigrateError};
+    use crate::Type;
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn key_mapping() {
+        let mut users = KeyMapper::<User>::new("users");
+        let accounts = KeyMapper::<User>::new("accounts");
+
+        let id = users.map(7).unwrap();
+        assert_eq!(id.to_u128() as u64, 7);
+        assert_eq!(users.map(7).unwrap(), id);
+        assert_ne!(accounts.id_for(7), id);
+        assert_ne!(users.id_for("7"), id);
+
+        let uuid = [0xab; 16];
+        assert_eq!(users.map(uuid).unwrap().to_bytes(), uuid);
+        assert_eq!(users.store().len(), 2);
+
+        // Pretend another key was already mapped to the identifier for "alice".
+        let mut store = users.into_store();
+        let id = KeyMapper::<User>::new("users").id_for("alice");
+        store
+            .insert(id, LegacyKey::String("bob").to_bytes())
+            .unwrap();
+
+        let mut users = KeyMapper::with_store("users", store);
+        assert!(matches!(
+            users.map("alice"),
+            Err(MigrateError::Collision { existing }) if existing == b"tbob"
+        ));
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{Id, IdBytes, Type};
+use minicbor::bytes::ByteArray;
+use minicbor::encode::{Error, Write};
+use minicbor::{decode, CborLen, Decode, Decoder, Encode, Encoder};
+
+impl<C, T: Type> Encode<C> for Id<T> {
+    fn encode<W: Write>(&self, e: &mut Encoder<W>, ctx: &mut C) -> Result<(), Error<W::Error>> {
+        ByteArray::from(*self.as_bytes()).encode(e, ctx)
+    }
+}
+
+impl<'b, C, T: Type> Decode<'b, C> for Id<T> {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, decode::Error> {
+        ByteArray::decode(d, ctx).map(|bytes| Self::new(IdBytes::from(bytes)))
+    }
+}
+
+impl<C, T: Type> CborLen<C> for Id<T> {
+    fn cbor_len(&self, ctx: &mut C) -> usize {
+        ByteArray::from(*self.as_bytes()).cbor_len(ctx)
+    }
+}
@@ -0,0 +1,75 @@
+//! Node.js bindings through napi-rs.
+//!
+//! The exported functions work on runtime prefixes, like [`Generic`]. An addon crate
+//! built as a `cdylib` with `@napi-rs/cli` registers them with
+//! `pub use souvenir::napi::*;`:
+//!
+//! ```js
+//! const { generate, parse, validate, format } = require("souvenir");
+//!
+//! const id = generate("user");
+//! const { prefix, bytes } = parse(id);
+//! validate(id, "user"); // true
+//! format(prefix, bytes) === id; // true
+//! ```
+
+use crate::Generic;
+use napi::bindgen_prelude::Buffer;
+use napi::{Error, Result, Status};
+use napi_derive::napi;
+
+/// An identifier split into its prefix and 16 bytes.
+#[napi(object)]
+pub struct ParsedId {
+    pub prefix: String,
+    pub bytes: Buffer,
+}
+
+/// Generate a new random identifier with the provided prefix.
+#[napi]
+pub fn generate(prefix: String) -> Result<String> {
+    Generic::random(prefix)
+        .map(|id| id.to_string())
+        .map_err(|e| Error::new(Status::InvalidArg, e.to_string()))
+}
+
+/// Parse an identifier, throwing if it is malformed or has a different prefix than the
+/// one provided.
+#[napi]
+pub fn parse(value: String, prefix: Option<String>) -> Result<ParsedId> {
+    let id = Generic::parse(&value).map_err(|e| Error::new(Status::InvalidArg, e.to_string()))?;
+
+    if let Some(prefix) = prefix.filter(|prefix| prefix != id.prefix()) {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("expected prefix `{}`, found `{}`", prefix, id.prefix()),
+        ));
+    }
+
+    Ok(ParsedId {
+        prefix: id.prefix().to_owned(),
+        bytes: id.as_bytes().to_vec().into(),
+    })
+}
+
+/// Format an identifier from its prefix and 16 bytes.
+#[napi]
+pub fn format(prefix: String, bytes: Buffer) -> Result<String> {
+    let value = bytes.as_ref().try_into().map_err(|_| {
+        Error::new(
+            Status::InvalidArg,
+            format!("identifier data must be 16 bytes, found {}", bytes.len()),
+        )
+    })?;
+
+    Generic::new(prefix, value)
+        .map(|id| id.to_string())
+        .map_err(|e| Error::new(Status::InvalidArg, e.to_string()))
+}
+
+/// Test to see if the provided string is a valid identifier, optionally with the
+/// provided prefix.
+#[napi]
+pub fn validate(value: String, prefix: Option<String>) -> bool {
+    Generic::parse(&value).is_ok_and(|id| prefix.is_none_or(|prefix| id.prefix() == prefix))
+}
@@ -0,0 +1,29 @@
+use crate::{Id, Type};
+use opentelemetry::trace::{SpanId, TraceId};
+
+impl<T: Type> Id<T> {
+    /// Reinterpret this identifier as an OpenTelemetry `TraceId`. Both are 16 bytes, so
+    /// the conversion is lossless.
+    pub fn to_trace_id(self) -> TraceId {
+        TraceId::from_bytes(self.to_bytes())
+    }
+
+    /// Derive a stable OpenTelemetry `SpanId` from this identifier by XOR-ing its high
+    /// and low 8 bytes. The same id always yields the same span id.
+    pub fn to_span_id(self) -> SpanId {
+        let value = self.to_u128();
+        SpanId::from_bytes((((value >> 64) as u64) ^ (value as u64)).to_be_bytes())
+    }
+}
+
+impl<T: Type> From<Id<T>> for TraceId {
+    fn from(value: Id<T>) -> Self {
+        value.to_trace_id()
+    }
+}
+
+impl<T: Type> From<TraceId> for Id<T> {
+    fn from(value: TraceId) -> Self {
+        Self::new(value.to_bytes())
+    }
+}
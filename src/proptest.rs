@@ -0,0 +1,98 @@
+//! Proptest strategies for identifiers.
+//!
+//! ```ignore
+//! proptest! {
+//!     #[test]
+//!     fn round_trip(id in souvenir::proptest::id::<User>()) {
+//!         prop_assert_eq!(id.to_string().parse::<Id<User>>()?, id);
+//!     }
+//! }
+//! ```
+
+use crate::{Id, Type};
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::num::u128::Any;
+use proptest::prelude::*;
+use proptest::strategy::Map;
+use std::ops::{Range, RangeInclusive};
+use std::time::SystemTime;
+
+/// Strategy producing uniformly random `Id<T>`s.
+pub fn id<T: Type + ?Sized>() -> impl Strategy<Value = Id<T>> + Clone {
+    any::<u128>().prop_map(Id::from)
+}
+
+/// Strategy producing `Id<T>`s whose value lies within the given range.
+pub fn in_range<T: Type + ?Sized>(range: RangeInclusive<u128>) -> impl Strategy<Value = Id<T>> {
+    range.prop_map(Id::from)
+}
+
+/// Strategy producing time-ordered `Id<T>`s created within the given range of times.
+///
+/// Times are truncated to whole milliseconds, as stored in the identifier, and the
+/// remaining bits are random. Generating values panics if the range is empty.
+pub fn timestamped<T: Type + ?Sized>(range: Range<SystemTime>) -> impl Strategy<Value = Id<T>> {
+    let start = Id::<T>::min_for_timestamp(range.start).to_u128();
+    let end = Id::<T>::min_for_timestamp(range.end).to_u128();
+
+    (start..end).prop_map(Id::from)
+}
+
+/// Strategy producing random `Id<T>`s, with the nil (all zero) id mixed in regularly.
+pub fn with_nil<T: Type + ?Sized>() -> impl Strategy<Value = Id<T>> {
+    prop_oneof![
+        1 => Just(Id::new([0; 16])),
+        9 => id::<T>(),
+    ]
+}
+
+impl<T: Type + ?Sized> Arbitrary for Id<T> {
+    type Parameters = ();
+    type Strategy = Map<Any, fn(u128) -> Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<u128>().prop_map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type};
+    use proptest::prelude::*;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+    use std::ops::Range;
+    use std::time::{Duration, SystemTime};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    fn start() -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+    }
+
+    fn window() -> Range<SystemTime> {
+        start()..start() + Duration::from_secs(60)
+    }
+
+    proptest! {
+        #[test]
+        fn timestamped_in_range(id in super::timestamped::<User>(window())) {
+            prop_assert!(window().contains(&id.created_at()));
+        }
+    }
+
+    #[test]
+    fn timestamped_single_millisecond() {
+        let strategy = super::timestamped(start()..start() + Duration::from_millis(1));
+        let id: Id<User> = strategy
+            .new_tree(&mut TestRunner::default())
+            .unwrap()
+            .current();
+
+        assert_eq!(id.created_at(), start());
+    }
+}
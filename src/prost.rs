@@ -0,0 +1,70 @@
+//! Protobuf interop for identifiers.
+//!
+//! An `Id<T>` can travel over protobuf in one of three ways:
+//!
+//! - as a `bytes` field holding the 16 big-endian bytes of the value
+//!   (`id.as_bytes().to_vec()` / `Id::try_from(&field[..])`),
+//! - as a `string` field holding the prefixed form
+//!   (`id.to_string()` / `field.parse()`),
+//! - as the [`Id`] message defined in `proto/souvenir.proto`, which carries
+//!   both the prefix and the value and validates the prefix on conversion.
+//!
+//! ```
+//! use souvenir::{Type, Id};
+//!
+//! struct User;
+//!
+//! impl Type for User {
+//!     const PREFIX: &'static str = "user";
+//! }
+//!
+//! let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+//! let message = souvenir::prost::Id::from(id);
+//! assert_eq!(message.prefix, "user");
+//!
+//! let id2: Id<User> = message.try_into().unwrap();
+//! assert_eq!(id, id2);
+//! ```
+
+use crate::{Error, Segment, Type};
+
+/// The `souvenir.Id` protobuf message.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Id {
+    /// The prefix of the identifier.
+    #[prost(string, tag = "1")]
+    pub prefix: String,
+    /// The 16 big-endian bytes of the identifier.
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: Vec<u8>,
+}
+
+impl ::prost::Name for Id {
+    const NAME: &'static str = "Id";
+    const PACKAGE: &'static str = "souvenir";
+}
+
+impl<T: Type> From<crate::Id<T>> for Id {
+    fn from(value: crate::Id<T>) -> Self {
+        Self {
+            prefix: T::PREFIX.to_owned(),
+            value: value.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl<T: Type> TryFrom<Id> for crate::Id<T> {
+    type Error = Error;
+
+    fn try_from(value: Id) -> Result<Self, Self::Error> {
+        if value.prefix != T::PREFIX {
+            return Err(Error::PrefixMismatch {
+                name: T::NAME,
+                expected: T::PREFIX,
+                actual: Segment::new(&value.prefix),
+            });
+        }
+
+        Self::try_from(&value.value[..])
+    }
+}
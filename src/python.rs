@@ -0,0 +1,127 @@
+//! Python bindings through PyO3.
+//!
+//! The `Id` class wraps a [`Generic`] identifier, since prefixes are only known at
+//! runtime on the Python side. [`souvenir`] is the module definition; an extension
+//! module crate built with maturin re-exports it:
+//!
+//! ```ignore
+//! use pyo3::prelude::*;
+//!
+//! #[pymodule]
+//! fn souvenir(m: &Bound<'_, PyModule>) -> PyResult<()> {
+//!     souvenir::python::souvenir(m)
+//! }
+//! ```
+//!
+//! ```python
+//! from souvenir import Id
+//!
+//! id = Id("user")
+//! parsed = Id.parse("user_02v58c5a3fy30k560qrtg4rb2k")
+//! assert parsed.prefix == "user"
+//! assert Id.test(str(id), prefix="user")
+//! ```
+
+use crate::Generic;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+/// A prefixed identifier exported to Python.
+#[pyclass(
+    name = "Id",
+    module = "souvenir",
+    frozen,
+    eq,
+    ord,
+    hash,
+    str,
+    from_py_object
+)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PyId {
+    inner: Generic,
+}
+
+#[pymethods]
+impl PyId {
+    /// Generate a new random identifier with the provided prefix.
+    #[new]
+    fn new(prefix: &str) -> PyResult<Self> {
+        Generic::random(prefix)
+            .map(|inner| Self { inner })
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Create an identifier with the provided prefix from its 16 bytes.
+    #[staticmethod]
+    fn from_bytes(prefix: &str, value: &[u8]) -> PyResult<Self> {
+        let value = value
+            .try_into()
+            .map_err(|_| PyValueError::new_err("identifier data must be 16 bytes"))?;
+
+        Generic::new(prefix, value)
+            .map(|inner| Self { inner })
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Parse an identifier, raising `ValueError` if it is malformed.
+    #[staticmethod]
+    fn parse(value: &str) -> PyResult<Self> {
+        Generic::parse(value)
+            .map(|inner| Self { inner })
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Test to see if the provided string is a valid identifier, optionally with the
+    /// provided prefix.
+    #[staticmethod]
+    #[pyo3(signature = (value, prefix = None))]
+    fn test(value: &str, prefix: Option<&str>) -> bool {
+        Generic::parse(value).is_ok_and(|id| prefix.is_none_or(|prefix| id.prefix() == prefix))
+    }
+
+    /// The prefix of this identifier.
+    #[getter]
+    fn prefix(&self) -> &str {
+        self.inner.prefix()
+    }
+
+    /// The 16 bytes of this identifier.
+    #[getter]
+    fn bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.inner.as_bytes())
+    }
+
+    fn __int__(&self) -> u128 {
+        self.inner.to_u128()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Id('{}')", self.inner)
+    }
+}
+
+impl Display for PyId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<Generic> for PyId {
+    fn from(value: Generic) -> Self {
+        Self { inner: value }
+    }
+}
+
+impl From<PyId> for Generic {
+    fn from(value: PyId) -> Self {
+        value.inner
+    }
+}
+
+/// Add the souvenir classes to a Python module.
+pub fn souvenir(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyId>()
+}
@@ -0,0 +1,13 @@
+use crate::{Id, Type};
+use quickcheck::{Arbitrary, Gen};
+
+impl<T: Type + ?Sized + 'static> Arbitrary for Id<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self::from(u128::arbitrary(g))
+    }
+
+    // Shrinks the underlying value towards zero, so failures minimize towards the nil id.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.to_u128().shrink().map(Self::from))
+    }
+}
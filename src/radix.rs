@@ -0,0 +1,86 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::{Error, Id, Type};
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Format the value of this identifier as an integer in the provided radix, using the
+    /// digits `0-9` and lowercase letters `a-z`, without the prefix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36.
+    #[cfg(feature = "alloc")]
+    pub fn to_radix_string(self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "radix to be between 2 and 36");
+
+        let mut value = self.to_u128();
+        let mut digits = Vec::new();
+
+        loop {
+            let digit = char::from_digit((value % radix as u128) as u32, radix)
+                .expect("digit to be below the radix");
+            digits.push(digit);
+            value /= radix as u128;
+
+            if value == 0 {
+                break;
+            }
+        }
+
+        digits.iter().rev().collect()
+    }
+
+    /// Attempt to parse an integer in the provided radix into an `Id<T>`, such as one
+    /// from [`Id::to_radix_string`].
+    ///
+    /// Letters may be in either case. Signs and empty strings are rejected, as are values
+    /// which don't fit in 128 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36.
+    pub fn from_str_radix(value: &str, radix: u32) -> Result<Self, Error> {
+        assert!((2..=36).contains(&radix), "radix to be between 2 and 36");
+
+        if value.is_empty() || !value.chars().all(|c| c.is_digit(radix)) {
+            return Err(Error::InvalidData);
+        }
+
+        let value = u128::from_str_radix(value, radix).map_err(|_| Error::InvalidData)?;
+
+        Self::new_checked(value.to_be_bytes())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type};
+
+    struct Order;
+
+    impl Type for Order {
+        const PREFIX: &'static str = "ord";
+    }
+
+    #[test]
+    fn radix_round_trip() {
+        let id = Id::<Order>::from(1_234_567_890u128);
+
+        assert_eq!(id.to_radix_string(10), "1234567890");
+        assert_eq!(id.to_radix_string(36), "kf12oi");
+        assert_eq!(Id::<Order>::from_str_radix("KF12OI", 36).unwrap(), id);
+        assert_eq!(Id::<Order>::from(0u128).to_radix_string(2), "0");
+
+        let max = Id::<Order>::from(u128::MAX);
+        assert_eq!(
+            Id::from_str_radix(&max.to_radix_string(7), 7).ok(),
+            Some(max)
+        );
+
+        assert!(Id::<Order>::from_str_radix("", 10).is_err());
+        assert!(Id::<Order>::from_str_radix("+1", 10).is_err());
+        assert!(Id::<Order>::from_str_radix("12", 2).is_err());
+        assert!(Id::<Order>::from_str_radix(&"z".repeat(30), 36).is_err());
+    }
+}
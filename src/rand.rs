@@ -1,9 +1,35 @@
 use crate::{Id, Type};
-use rand::random;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
 
 impl<T: Type> Id<T> {
-    /// Generate an Id<T> with a random value
+    /// Generate an Id<T> with a random value, from the thread-local random number generator
+    /// or the [configured](crate::Config::rng) source of randomness.
+    #[cfg(feature = "std")]
     pub fn random() -> Self {
-        Self::new(random())
+        Self::random_with(&mut crate::config::ConfiguredRng)
+    }
+
+    /// Generate an Id<T> with a random value from the provided random number generator.
+    ///
+    /// Without the `std` feature there is no thread-local generator, so this is how
+    /// random identifiers are created on `no_std` targets.
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+}
+
+impl<T: Type> Distribution<Id<T>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Id<T> {
+        loop {
+            let id = Id::new(rng.gen());
+
+            if id.is_issuable() {
+                #[cfg(feature = "std")]
+                crate::hooks::generated::<T>(core::any::type_name::<R>(), id.as_bytes());
+
+                return id;
+            }
+        }
     }
 }
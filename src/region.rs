@@ -0,0 +1,237 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(all(feature = "rand", feature = "std"))]
+use std::{sync::Mutex, time::SystemTime};
+
+#[cfg(all(feature = "rand", feature = "std"))]
+use crate::config::ConfiguredRng;
+#[cfg(all(feature = "rand", feature = "std"))]
+use crate::time::{to_millis, TIMESTAMP_BITS};
+use crate::{Generator, Id, IdBytes, Type};
+#[cfg(all(feature = "rand", feature = "std"))]
+use rand::Rng;
+
+/// Index of the byte holding the region code, directly after the 48-bit timestamp of
+/// time-ordered identifiers.
+pub(crate) const REGION_BYTE: usize = 6;
+
+/// A generator which stamps a region code into the values of another generator.
+///
+/// The region code replaces byte 6 of each value, bits 72 to 79 counting from the least
+/// significant bit, directly after the timestamp of time-ordered values, so it can be
+/// read back with [`Id::region`] and the identifier routed with a [`Router`] without a
+/// directory lookup. The stamped byte is taken from the inner generator's randomness,
+/// which leaves 120 random bits with [`Random`](crate::Random) and 72 with
+/// [`Timestamped`](crate::Timestamped).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Regional<G: Generator> {
+    generator: G,
+    region: u8,
+}
+
+impl<G: Generator> Regional<G> {
+    /// Create a generator stamping the provided region code into values from `generator`.
+    pub const fn new(generator: G, region: u8) -> Self {
+        Self { generator, region }
+    }
+
+    /// Get the region code stamped into values.
+    pub const fn region(&self) -> u8 {
+        self.region
+    }
+}
+
+impl<G: Generator> Generator for Regional<G> {
+    fn next_value(&self) -> IdBytes {
+        let mut value = self.generator.next_value();
+        value[REGION_BYTE] = self.region;
+        value
+    }
+}
+
+/// A time-ordered generator for deployments spanning several regions, which never
+/// produces the same value twice across regions or workers.
+///
+/// Values are laid out as follows, from the most significant bit:
+///
+/// | Bits | Field     | Range                                               |
+/// |------|-----------|-----------------------------------------------------|
+/// | 48   | timestamp | milliseconds since the Unix epoch, until year 10889 |
+/// | 8    | region    | 256 regions, read with [`Id::region`]               |
+/// | 16   | worker    | 65536 workers per region                            |
+/// | 24   | sequence  | 16777216 values per millisecond per worker          |
+/// | 32   | random    | unpredictable suffix                                |
+///
+/// Each `(region, worker)` pair must be assigned to at most one running generator. Values
+/// from different pairs never collide, and a single generator never repeats a timestamp
+/// and sequence: if the clock goes backwards the last timestamp is reused, and once the
+/// sequence is exhausted the timestamp is advanced by a millisecond. The only way to
+/// collide is for two generators to share a pair, or for a restarted generator to see an
+/// earlier clock than the one it replaced; the random suffix makes a collision between
+/// such values a 1 in 2^32 chance.
+#[cfg(all(feature = "rand", feature = "std"))]
+#[derive(Debug)]
+pub struct MultiRegion {
+    region: u8,
+    worker: u16,
+    /// The last timestamp and the next sequence number for it.
+    state: Mutex<(u64, u32)>,
+}
+
+#[cfg(all(feature = "rand", feature = "std"))]
+impl MultiRegion {
+    /// Maximum sequence number within a millisecond.
+    const MAX_SEQUENCE: u32 = (1 << 24) - 1;
+
+    /// Create a generator for the provided region and worker.
+    pub const fn new(region: u8, worker: u16) -> Self {
+        Self {
+            region,
+            worker,
+            state: Mutex::new((0, 0)),
+        }
+    }
+
+    /// Get the region code stamped into values.
+    pub const fn region(&self) -> u8 {
+        self.region
+    }
+
+    /// Get the worker number stamped into values.
+    pub const fn worker(&self) -> u16 {
+        self.worker
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "std"))]
+impl Generator for MultiRegion {
+    fn next_value(&self) -> IdBytes {
+        let (millis, sequence) = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            let (last, next) = *state;
+            let now = to_millis(SystemTime::now());
+
+            *state = match now.cmp(&last) {
+                core::cmp::Ordering::Greater => (now, 0),
+                _ if next <= Self::MAX_SEQUENCE => (last, next),
+                _ => {
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::exhausted("multi_region");
+
+                    (last + 1, 0)
+                }
+            };
+
+            let (millis, sequence) = *state;
+            state.1 += 1;
+            (millis, sequence)
+        };
+
+        let value = (millis as u128) << (128 - TIMESTAMP_BITS)
+            | (self.region as u128) << 72
+            | (self.worker as u128) << 56
+            | (sequence as u128) << 32
+            | ConfiguredRng.gen::<u32>() as u128;
+
+        value.to_be_bytes()
+    }
+}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Get the region code of this identifier, stamped by a [`Regional`] or `MultiRegion`
+    /// generator.
+    ///
+    /// Identifiers from other generators return an arbitrary code.
+    pub fn region(self) -> u8 {
+        self.as_bytes()[REGION_BYTE]
+    }
+}
+
+/// A table mapping region codes to endpoints, for routing requests by identifier.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct Router<E> {
+    endpoints: BTreeMap<u8, E>,
+}
+
+#[cfg(feature = "alloc")]
+impl<E> Router<E> {
+    /// Create an empty router.
+    pub const fn new() -> Self {
+        Self {
+            endpoints: BTreeMap::new(),
+        }
+    }
+
+    /// Set the endpoint for a region code, returning the previous endpoint.
+    pub fn insert(&mut self, region: u8, endpoint: E) -> Option<E> {
+        self.endpoints.insert(region, endpoint)
+    }
+
+    /// Get the endpoint for a region code.
+    pub fn get(&self, region: u8) -> Option<&E> {
+        self.endpoints.get(&region)
+    }
+
+    /// Get the endpoint for the region of an identifier.
+    pub fn route<T: Type + ?Sized>(&self, id: Id<T>) -> Option<&E> {
+        self.get(id.region())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E> Default for Router<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E> FromIterator<(u8, E)> for Router<E> {
+    fn from_iter<I: IntoIterator<Item = (u8, E)>>(iter: I) -> Self {
+        Self {
+            endpoints: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Regional, Router, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", feature = "std"))]
+    fn regional_routing() {
+        let generator = Regional::new(crate::Random, 2);
+        let router = Router::from_iter([(1, "us-east"), (2, "eu-west")]);
+
+        for _ in 0..100 {
+            let id = Id::<User>::generate(&generator);
+
+            assert_eq!(id.region(), 2);
+            assert_eq!(router.route(id), Some(&"eu-west"));
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", feature = "std"))]
+    fn multi_region_values() {
+        let generator = crate::MultiRegion::new(3, 513);
+        let mut last = Id::<User>::generate(&generator);
+
+        for _ in 0..10_000 {
+            let id = Id::<User>::generate(&generator);
+
+            assert_eq!(id.region(), 3);
+            assert_eq!(id.as_bytes()[7..9], 513u16.to_be_bytes());
+            assert!(id > last);
+            last = id;
+        }
+    }
+}
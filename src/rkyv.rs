@@ -0,0 +1,24 @@
+use crate::{Id, IdBytes, Type};
+use rkyv::rancor::Fallible;
+use rkyv::{Archive, Deserialize, Place, Serialize};
+
+impl<T: Type> Archive for Id<T> {
+    type Archived = IdBytes;
+    type Resolver = ();
+
+    fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+        out.write(*self.as_bytes());
+    }
+}
+
+impl<T: Type, S: Fallible + ?Sized> Serialize<S> for Id<T> {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<T: Type, D: Fallible + ?Sized> Deserialize<Id<T>, D> for IdBytes {
+    fn deserialize(&self, _: &mut D) -> Result<Id<T>, D::Error> {
+        Ok(Id::new(*self))
+    }
+}
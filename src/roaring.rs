@@ -0,0 +1,72 @@
+//! Storing sets of identifiers in roaring bitmaps.
+//!
+//! A `RoaringTreemap` holds 64-bit integers, so identifiers are stored by their value when
+//! it fits in the low 64 bits, as with [`Sequential`](crate::Sequential) identifiers or
+//! those converted from database serials. Roaring bitmaps compress dense runs of values
+//! well, so sets of tens of millions of sequential identifiers take a few megabytes.
+//! Random and time-ordered identifiers use all 128 bits and can't be stored; converting a
+//! set holding one fails with [`Error::InvalidData`].
+
+use crate::{Error, Id, IdSet, Type};
+use ::roaring::RoaringTreemap;
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Get the value stored for this identifier in a roaring bitmap, if it fits.
+    pub fn to_roaring_value(self) -> Option<u64> {
+        u64::try_from(self.to_u128()).ok()
+    }
+
+    /// Test whether a roaring bitmap holds this identifier.
+    pub fn is_in_roaring(self, bitmap: &RoaringTreemap) -> bool {
+        self.to_roaring_value()
+            .is_some_and(|value| bitmap.contains(value))
+    }
+}
+
+impl<T: Type + ?Sized> IdSet<T> {
+    /// Convert this set into a roaring bitmap.
+    ///
+    /// Fails with [`Error::InvalidData`] if any identifier doesn't fit in 64 bits.
+    pub fn to_roaring(&self) -> Result<RoaringTreemap, Error> {
+        let mut values = self
+            .iter()
+            .map(|id| id.to_roaring_value().ok_or(Error::InvalidData))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        values.sort_unstable();
+
+        Ok(RoaringTreemap::from_sorted_iter(values).expect("values to be sorted"))
+    }
+
+    /// Create a set holding the identifiers in a roaring bitmap.
+    pub fn from_roaring(bitmap: &RoaringTreemap) -> Self {
+        bitmap.iter().map(|value| Id::from(value as u128)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Error, Id, IdSet, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn roaring_round_trip() {
+        let set: IdSet<User> = (0..1000u128).map(|i| Id::from(i * 3)).collect();
+        let bitmap = set.to_roaring().unwrap();
+
+        assert_eq!(bitmap.len(), 1000);
+        assert!(Id::<User>::from(999u128).is_in_roaring(&bitmap));
+        assert!(!Id::<User>::from(1000u128).is_in_roaring(&bitmap));
+        assert!(!Id::<User>::from(u128::MAX).is_in_roaring(&bitmap));
+        assert_eq!(IdSet::from_roaring(&bitmap), set);
+
+        let mut set = set;
+        set.insert(Id::from(1u128 << 64));
+        assert!(matches!(set.to_roaring(), Err(Error::InvalidData)));
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{Error, Id, Type};
+use rocket::form::{self, FromFormField, ValueField};
+use rocket::request::FromParam;
+
+impl<'a, T: Type> FromParam<'a> for Id<T> {
+    type Error = Error;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        Self::parse(param)
+    }
+}
+
+impl<'v, T: Type> FromFormField<'v> for Id<T> {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        Self::parse(field.value).map_err(|e| {
+            form::Error::validation(format!("invalid `{}` identifier: {}", T::PREFIX, e)).into()
+        })
+    }
+}
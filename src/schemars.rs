@@ -0,0 +1,49 @@
+use crate::encoding::{description, pattern, EXAMPLE};
+use crate::{Id, Type};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use std::borrow::Cow;
+
+impl<T: Type> JsonSchema for Id<T> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("Id_{}", T::PREFIX).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("souvenir::Id<{}>", T::PREFIX).into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": description::<T>(),
+            "pattern": pattern(T::PREFIX),
+            "examples": [Id::<T>::new(EXAMPLE).to_string()],
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type};
+    use regex_lite::Regex;
+    use schemars::schema_for;
+
+    struct Invoice;
+
+    impl Type for Invoice {
+        const PREFIX: &'static str = "inv";
+        const UPPERCASE: bool = true;
+    }
+
+    #[test]
+    fn example_matches_pattern() {
+        let schema = schema_for!(Id<Invoice>);
+        let pattern = Regex::new(schema.get("pattern").unwrap().as_str().unwrap()).unwrap();
+        let example = schema.get("examples").unwrap()[0].as_str().unwrap();
+
+        assert_eq!(example, "inv_02V58C5A3FY30K560QRTG4RB2K");
+        assert!(pattern.is_match(example));
+        assert!(pattern.is_match(&example.to_ascii_lowercase()));
+        assert!(!pattern.is_match("inv_02V58C5A3FY30K560QRTG4RB2I"));
+    }
+}
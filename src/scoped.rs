@@ -0,0 +1,151 @@
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use crate::{Error, Id, Type};
+
+/// An identifier which is only meaningful within the scope of a parent identifier, such as
+/// a user within a tenant organization.
+///
+/// Scoped identifiers are formatted as the parent and child separated by a `/`, like
+/// `org_7zzzzzzzzzzzzzzzzzzzzzzzzz/user_02v58c5a3fy30k560qrtg4rb2k`, and are ordered by
+/// parent, then by child, so the children of a parent sort together.
+pub struct ScopedId<P: Type + ?Sized, C: Type + ?Sized> {
+    parent: Id<P>,
+    child: Id<C>,
+}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> ScopedId<P, C> {
+    /// Create a new scoped identifier for a child within the provided parent.
+    pub fn new(parent: Id<P>, child: Id<C>) -> Self {
+        Self { parent, child }
+    }
+
+    /// Get the parent identifier.
+    pub fn parent(self) -> Id<P> {
+        self.parent
+    }
+
+    /// Get the child identifier.
+    pub fn child(self) -> Id<C> {
+        self.child
+    }
+
+    /// Split this scoped identifier into its parent and child.
+    pub fn into_parts(self) -> (Id<P>, Id<C>) {
+        (self.parent, self.child)
+    }
+
+    /// Test to see if the provided string is a valid `ScopedId<P, C>`.
+    pub fn test(value: &str) -> bool {
+        Self::parse(value).is_ok()
+    }
+
+    /// Attempt to parse the provided string into a `ScopedId<P, C>`.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let (parent, child) = value.split_once('/').ok_or(Error::InvalidData)?;
+
+        Ok(Self::new(Id::parse(parent)?, Id::parse(child)?))
+    }
+}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> Copy for ScopedId<P, C> {}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> Clone for ScopedId<P, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> PartialEq for ScopedId<P, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.parent == other.parent && self.child == other.child
+    }
+}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> Eq for ScopedId<P, C> {}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> PartialOrd for ScopedId<P, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> Ord for ScopedId<P, C> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.parent, self.child).cmp(&(other.parent, other.child))
+    }
+}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> Hash for ScopedId<P, C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.parent.hash(state);
+        self.child.hash(state);
+    }
+}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> Debug for ScopedId<P, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> Display for ScopedId<P, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/{}", self.parent, self.child)
+    }
+}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> FromStr for ScopedId<P, C> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> From<(Id<P>, Id<C>)> for ScopedId<P, C> {
+    fn from((parent, child): (Id<P>, Id<C>)) -> Self {
+        Self::new(parent, child)
+    }
+}
+
+impl<P: Type + ?Sized, C: Type + ?Sized> From<ScopedId<P, C>> for (Id<P>, Id<C>) {
+    fn from(value: ScopedId<P, C>) -> Self {
+        value.into_parts()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, ScopedId, Type};
+
+    struct Org;
+
+    impl Type for Org {
+        const PREFIX: &'static str = "org";
+    }
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn scoped_round_trip() {
+        let value = "org_7zzzzzzzzzzzzzzzzzzzzzzzzz/user_02v58c5a3fy30k560qrtg4rb2k";
+        let id: ScopedId<Org, User> = ScopedId::parse(value).unwrap();
+
+        assert_eq!(
+            id.parent(),
+            Id::parse("org_7zzzzzzzzzzzzzzzzzzzzzzzzz").unwrap()
+        );
+        assert_eq!(
+            id.child(),
+            Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap()
+        );
+        assert_eq!(id.to_string(), value);
+        assert!(ScopedId::<User, Org>::parse(value).is_err());
+    }
+}
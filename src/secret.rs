@@ -0,0 +1,92 @@
+use crate::encoding::format_base32;
+use crate::{Id, Type};
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+
+/// Number of value characters shown by the redacted form of a `SecretId<T>`.
+const VISIBLE: usize = 4;
+
+/// A sensitive `Id<T>` that is redacted when displayed.
+///
+/// `Display` and `Debug` print only the prefix and the first few characters of the value,
+/// so secret identifiers such as password reset ids don't leak into logs by accident. The
+/// full identifier is available through [`SecretId::expose`].
+///
+/// ```
+/// use souvenir::{Id, SecretId, Type};
+///
+/// struct Invite;
+///
+/// impl Type for Invite {
+///     const PREFIX: &'static str = "inv";
+/// }
+///
+/// let id: Id<Invite> = Id::parse("inv_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+/// let secret = SecretId::new(id);
+///
+/// assert_eq!(secret.to_string(), "inv_02v5…[redacted]");
+/// assert_eq!(secret.expose(), &id);
+/// ```
+pub struct SecretId<T: Type + ?Sized> {
+    id: Id<T>,
+}
+
+impl<T: Type + ?Sized> SecretId<T> {
+    /// Wrap the provided identifier.
+    pub fn new(id: Id<T>) -> Self {
+        Self { id }
+    }
+
+    /// Get the wrapped identifier.
+    pub fn expose(&self) -> &Id<T> {
+        &self.id
+    }
+
+    /// Get mutable access to the wrapped identifier.
+    #[cfg(feature = "zeroize")]
+    pub(crate) fn expose_mut(&mut self) -> &mut Id<T> {
+        &mut self.id
+    }
+}
+
+impl<T: Type + ?Sized> Clone for SecretId<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.id)
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq for SecretId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T: Type + ?Sized> Eq for SecretId<T> {}
+
+impl<T: Type + ?Sized> Hash for SecretId<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<T: Type + ?Sized> From<Id<T>> for SecretId<T> {
+    fn from(value: Id<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Type + ?Sized> Debug for SecretId<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl<T: Type + ?Sized> Display for SecretId<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let value = format_base32(*self.id.as_bytes());
+        let value = core::str::from_utf8(&value[..VISIBLE])
+            .expect("only ascii bytes should be in the buffer");
+
+        write!(f, "{}_{}…[redacted]", T::PREFIX, value)
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{Id, Type};
+use crate::{Generic, Id, Type};
+use alloc::string::{String, ToString};
 use serde::de::Error;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -18,6 +19,26 @@ impl<'de, T: Type> Deserialize<'de> for Id<T> {
     {
         <String as Deserialize<'de>>::deserialize(deserializer)
             .map(|str| Self::parse(&str))?
-            .map_err(|e| Error::custom(e))
+            .map_err(Error::custom)
+    }
+}
+
+impl Serialize for Generic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_string().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Generic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <String as Deserialize<'de>>::deserialize(deserializer)
+            .map(|str| Self::parse(&str))?
+            .map_err(Error::custom)
     }
 }
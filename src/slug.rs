@@ -0,0 +1,77 @@
+use core::fmt::{Display, Formatter, Write};
+
+use crate::{Error, Id, Type};
+
+/// An identifier displayed with a human-readable slug, like `post_…-my-first-post`.
+///
+/// Created with [`Id::with_slug`].
+pub struct WithSlug<'a, T: Type + ?Sized> {
+    id: Id<T>,
+    slug: &'a str,
+}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Display this identifier followed by a slug made from the provided text.
+    ///
+    /// ASCII letters and digits in the text are kept in lowercase, and every other run of
+    /// characters becomes a single `-`, so `"My First Post!"` is written as
+    /// `-my-first-post`. Text without any letters or digits adds no slug.
+    pub fn with_slug(self, text: &str) -> WithSlug<'_, T> {
+        WithSlug {
+            id: self,
+            slug: text,
+        }
+    }
+
+    /// Attempt to parse the provided string into an `Id<T>`, ignoring any slug after the
+    /// identifier.
+    pub fn parse_slugged(value: &str) -> Result<Self, Error> {
+        let (prefix, rest) = value.split_once('_').ok_or(Error::InvalidData)?;
+        let end = rest.find('-').unwrap_or(rest.len());
+
+        Self::parse(&value[..prefix.len() + 1 + end])
+    }
+}
+
+impl<T: Type + ?Sized> Display for WithSlug<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.id)?;
+
+        for word in self
+            .slug
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+        {
+            f.write_char('-')?;
+
+            for c in word.chars() {
+                f.write_char(c.to_ascii_lowercase())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type};
+
+    struct Post;
+
+    impl Type for Post {
+        const PREFIX: &'static str = "post";
+    }
+
+    #[test]
+    fn slug_round_trip() {
+        let id = Id::<Post>::parse("post_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+        let slugged = id.with_slug("My First Post!").to_string();
+
+        assert_eq!(slugged, "post_02v58c5a3fy30k560qrtg4rb2k-my-first-post");
+        assert_eq!(id.with_slug(" ?! ").to_string(), id.to_string());
+        assert_eq!(Id::<Post>::parse_slugged(&slugged).unwrap(), id);
+        assert_eq!(Id::<Post>::parse_slugged(&id.to_string()).unwrap(), id);
+        assert!(Id::<Post>::parse(&slugged).is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{Id, IdBytes, Type};
+use speedy::{Context, Readable, Reader, Writable, Writer};
+
+impl<'a, C: Context, T: Type> Readable<'a, C> for Id<T> {
+    fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        let mut value = IdBytes::default();
+        reader.read_bytes(&mut value)?;
+        Ok(Self::new(value))
+    }
+
+    fn minimum_bytes_needed() -> usize {
+        16
+    }
+}
+
+impl<C: Context, T: Type> Writable<C> for Id<T> {
+    fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        writer.write_bytes(self.as_bytes())
+    }
+
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Ok(16)
+    }
+}
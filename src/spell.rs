@@ -0,0 +1,75 @@
+use core::fmt::{Display, Formatter};
+
+use crate::encoding::format_base32;
+use crate::{Id, Type};
+
+/// Words for the digits in identifier values.
+const DIGITS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Words for the letters in identifier values.
+const LETTERS: [&str; 26] = [
+    "alfa", "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel", "india", "juliett",
+    "kilo", "lima", "mike", "november", "oscar", "papa", "quebec", "romeo", "sierra", "tango",
+    "uniform", "victor", "whiskey", "x-ray", "yankee", "zulu",
+];
+
+/// The value of an identifier spelled out with the NATO phonetic alphabet, like
+/// `zero two victor five eight …`.
+///
+/// Created with [`Id::spell`].
+pub struct Spelled<T: Type + ?Sized> {
+    id: Id<T>,
+}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Spell out the value of this identifier with the NATO phonetic alphabet, for
+    /// reading it over the phone.
+    ///
+    /// Each of the 26 value characters becomes one word, separated by spaces. The prefix
+    /// and environment are left out, since they're ordinary words which are read as
+    /// they are.
+    pub fn spell(self) -> Spelled<T> {
+        Spelled { id: self }
+    }
+}
+
+impl<T: Type + ?Sized> Display for Spelled<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (i, c) in format_base32(self.id.to_bytes()).into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+
+            f.write_str(match c {
+                b'0'..=b'9' => DIGITS[(c - b'0') as usize],
+                _ => LETTERS[(c - b'a') as usize],
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn spelled_value() {
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+
+        assert_eq!(
+            id.spell().to_string(),
+            "zero two victor five eight charlie five alfa three foxtrot yankee three zero \
+             kilo five six zero quebec romeo tango golf four romeo bravo two kilo"
+        );
+    }
+}
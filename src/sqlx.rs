@@ -1,6 +1,6 @@
 #[cfg(feature = "sqlx-postgres")]
 mod pg {
-    use crate::{Id, Type};
+    use crate::{Generic, Id, Type};
     use sqlx::postgres::{
         types::Oid, PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef,
         Postgres,
@@ -35,11 +35,41 @@ mod pg {
             .map_err(Into::into)
         }
     }
+
+    impl sqlx::Type<Postgres> for Generic {
+        fn type_info() -> PgTypeInfo {
+            <&str as sqlx::Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <&str as sqlx::Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl PgHasArrayType for Generic {
+        fn array_type_info() -> PgTypeInfo {
+            <&str as PgHasArrayType>::array_type_info()
+        }
+    }
+
+    impl Encode<'_, Postgres> for Generic {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            <String as Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl Decode<'_, Postgres> for Generic {
+        fn decode(value: PgValueRef) -> Result<Self, BoxDynError> {
+            <&str as Decode<Postgres>>::decode(value)?
+                .parse()
+                .map_err(Into::into)
+        }
+    }
 }
 
 #[cfg(feature = "sqlx-mysql")]
 mod mysql {
-    use crate::{Id, Type};
+    use crate::{Generic, Id, Type};
     use sqlx::mysql::{MySql, MySqlTypeInfo, MySqlValueRef};
     use sqlx::{encode::IsNull, error::BoxDynError, Decode, Encode};
 
@@ -65,11 +95,35 @@ mod mysql {
             Self::try_from(bytes).map_err(Into::into)
         }
     }
+
+    impl sqlx::Type<MySql> for Generic {
+        fn type_info() -> MySqlTypeInfo {
+            <&str as sqlx::Type<MySql>>::type_info()
+        }
+
+        fn compatible(ty: &MySqlTypeInfo) -> bool {
+            <&str as sqlx::Type<MySql>>::compatible(ty)
+        }
+    }
+
+    impl Encode<'_, MySql> for Generic {
+        fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+            <String as Encode<'_, MySql>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl Decode<'_, MySql> for Generic {
+        fn decode(value: MySqlValueRef) -> Result<Self, BoxDynError> {
+            <&str as Decode<MySql>>::decode(value)?
+                .parse()
+                .map_err(Into::into)
+        }
+    }
 }
 
 #[cfg(feature = "sqlx-sqlite")]
 mod sqlite {
-    use crate::{Id, Type};
+    use crate::{Generic, Id, Type};
     use sqlx::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
     use sqlx::{encode::IsNull, error::BoxDynError, Decode, Encode};
     use std::borrow::Cow;
@@ -101,4 +155,32 @@ mod sqlite {
                 .map_err(Into::into)
         }
     }
+
+    impl sqlx::Type<Sqlite> for Generic {
+        fn type_info() -> SqliteTypeInfo {
+            <&str as sqlx::Type<Sqlite>>::type_info()
+        }
+
+        fn compatible(ty: &SqliteTypeInfo) -> bool {
+            <&str as sqlx::Type<Sqlite>>::compatible(ty)
+        }
+    }
+
+    impl<'q> Encode<'q, Sqlite> for Generic {
+        fn encode_by_ref(
+            &self,
+            args: &mut Vec<SqliteArgumentValue<'q>>,
+        ) -> Result<IsNull, BoxDynError> {
+            args.push(SqliteArgumentValue::Text(Cow::Owned(self.to_string())));
+            Ok(IsNull::No)
+        }
+    }
+
+    impl Decode<'_, Sqlite> for Generic {
+        fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+            <&str as Decode<Sqlite>>::decode(value)?
+                .parse()
+                .map_err(Into::into)
+        }
+    }
 }
@@ -0,0 +1,17 @@
+use crate::{Id, Type};
+use subtle::{Choice, ConstantTimeEq};
+
+/// Compare identifiers in constant time.
+///
+/// The `PartialEq` implementation of `Id<T>` may return as soon as the first differing
+/// byte is found. When an identifier acts as a bearer token, such as a password reset or
+/// invite id looked up from user input, compare it against the stored value with
+/// `ct_eq` instead so the comparison time does not reveal how much of it was guessed.
+///
+/// Identifiers used this way should be generated randomly, never from sequential or
+/// time-ordered generators, and kept out of logs.
+impl<T: Type + ?Sized> ConstantTimeEq for Id<T> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_bytes().ct_eq(other.as_bytes())
+    }
+}
@@ -0,0 +1,248 @@
+//! Assertions for testing identifier types in downstream crates.
+//!
+//! Each entity type only needs one test to check that its identifiers survive every
+//! conversion souvenir offers:
+//!
+//! ```
+//! use souvenir::Type;
+//!
+//! struct User;
+//!
+//! impl Type for User {
+//!     const PREFIX: &'static str = "user";
+//! }
+//!
+//! souvenir::testing::assert_roundtrip::<User>();
+//! ```
+//!
+//! This covers the `Display` form, in both cases, with `Id::parse`, `FromStr` and
+//! `TryFrom<&str>`, bytes and integers, URL components, Relay global IDs and, with the
+//! `serde` feature, deserializing the formatted form. Database encodings need a
+//! connection, so they are not covered.
+//!
+//! [`test_vectors`] exports the canonical encodings checked by this crate, for testing
+//! implementations of the format in other languages.
+
+use std::fmt::Write;
+
+use crate::encoding::stringify_base32;
+use crate::{Error, Generic, Id, Type};
+
+/// Values checked by [`assert_roundtrip`], from the smallest to the largest.
+const SAMPLES: [u128; 5] = [
+    0,
+    1,
+    0x02d9_50c2_a86f_f0c1_3298_17c6_a04c_2c53,
+    u64::MAX as u128,
+    u128::MAX,
+];
+
+/// Assert that identifiers of type `T` round trip through every supported conversion.
+///
+/// A fixed set of values is checked, including zero and the maximum value. Values
+/// rejected by [`Type::validate`] are skipped; use [`assert_roundtrip_value`] to check
+/// the values valid for the type.
+///
+/// # Panics
+///
+/// Panics if any conversion doesn't return the original identifier.
+pub fn assert_roundtrip<T: Type>() {
+    for value in SAMPLES {
+        if let Ok(id) = Id::<T>::new_checked(value.to_be_bytes()) {
+            assert_roundtrip_value(id);
+        }
+    }
+
+    assert_prefix_checked::<T>();
+}
+
+/// Assert that an identifier round trips through every supported conversion.
+///
+/// # Panics
+///
+/// Panics if any conversion doesn't return the original identifier.
+pub fn assert_roundtrip_value<T: Type>(id: Id<T>) {
+    let string = id.to_string();
+
+    assert_eq!(
+        Id::<T>::parse(&string).ok(),
+        Some(id),
+        "parsing `{}`",
+        string
+    );
+    assert_eq!(
+        string.parse::<Id<T>>().ok(),
+        Some(id),
+        "`FromStr` for `{}`",
+        string
+    );
+    assert_eq!(Id::<T>::try_from(string.as_str()).ok(), Some(id));
+    assert!(Id::<T>::test(&string), "testing `{}`", string);
+
+    let uppercase = format!("{:#}", id);
+    assert_eq!(
+        Id::<T>::parse(&uppercase).ok(),
+        Some(id),
+        "parsing `{}`",
+        uppercase
+    );
+
+    assert_eq!(Id::<T>::try_from(&id.to_bytes()[..]).ok(), Some(id));
+    assert_eq!(Id::<T>::from(id.to_u128()), id);
+    assert_eq!(Id::<T>::from(id.to_i128()), id);
+
+    let component = id.to_url_component();
+    assert_eq!(Id::<T>::parse_url_component(&component).ok(), Some(id));
+
+    let global = id.to_global_id();
+    assert_eq!(Id::<T>::from_global_id(&global).ok(), Some(id));
+
+    #[cfg(feature = "serde")]
+    {
+        use serde::de::value::{Error, StrDeserializer};
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+
+        let deserializer: StrDeserializer<'_, Error> = string.as_str().into_deserializer();
+        assert_eq!(Id::<T>::deserialize(deserializer).ok(), Some(id));
+    }
+}
+
+/// Assert that identifiers of type `T` can't be parsed from identifiers with another
+/// prefix.
+///
+/// # Panics
+///
+/// Panics if an identifier with a different prefix parses as an `Id<T>`.
+pub fn assert_prefix_checked<T: Type>() {
+    let value = Id::<T>::new([0; 16]).to_string();
+    let value = &value[T::PREFIX.len()..];
+
+    for prefix in ["", "x", "other"] {
+        if prefix != T::PREFIX {
+            let foreign = format!("{}{}", prefix, value);
+            assert!(Id::<T>::parse(&foreign).is_err(), "parsing `{}`", foreign);
+        }
+    }
+}
+
+/// Values and prefixes exported by [`test_vectors`].
+const VECTORS: [(&str, u128); 8] = [
+    ("user", 0),
+    ("user", 1),
+    ("user", 0x02d9_50c2_a86f_f0c1_3298_17c6_a04c_2c53),
+    ("org", 0x0189_0a5d_ac96_774b_bcce_b302_099a_8057),
+    ("a", u64::MAX as u128),
+    ("Key2", 1 << 64),
+    ("evt", 0xaaaa_aaaa_aaaa_aaaa_5555_5555_5555_5555),
+    ("user", u128::MAX),
+];
+
+/// Strings exported by [`test_vectors`] which are not valid identifiers.
+const INVALID: [&str; 10] = [
+    "",
+    "user",
+    "user_",
+    "user_02v58c5a3fy30k560qrtg4rb2",
+    "user_02v58c5a3fy30k560qrtg4rb2kk",
+    "user_8zzzzzzzzzzzzzzzzzzzzzzzzz",
+    "user_02v58c5a3fy30k560qrtg4rb2u",
+    "user_02V58C5A3FY30K560QRTG4RB2k",
+    "user_02v58c5a3fy30k560qrtg4rb2l",
+    "user_02v58c5a3fy30k560qrtg4rb2k ",
+];
+
+/// Get canonical test vectors for the identifier format as a JSON document.
+///
+/// The document has a `valid` array of `{"prefix", "hex", "string"}` objects, pairing
+/// the 16 bytes of an identifier in hexadecimal with its formatted string, and an
+/// `invalid` array of `{"string", "error"}` objects naming the [`Error`] variant returned
+/// when parsing the string. Environment tags are never included.
+pub fn test_vectors() -> String {
+    let mut out = String::from("{\n  \"valid\": [");
+
+    for (i, (prefix, value)) in VECTORS.into_iter().enumerate() {
+        let string =
+            stringify_base32(value.to_be_bytes()).expect("id value to stringify correctly");
+
+        write!(
+            out,
+            "{}\n    {{\"prefix\": \"{}\", \"hex\": \"{:032x}\", \"string\": \"{}_{}\"}}",
+            if i == 0 { "" } else { "," },
+            prefix,
+            value,
+            prefix,
+            string
+        )
+        .expect("writing to a string to succeed");
+    }
+
+    out.push_str("\n  ],\n  \"invalid\": [");
+
+    for (i, string) in INVALID.into_iter().enumerate() {
+        let error = match Generic::parse(string) {
+            Ok(_) => unreachable!("invalid test vector `{}` to fail parsing", string),
+            Err(Error::InvalidPrefix) => "InvalidPrefix",
+            Err(_) => "InvalidData",
+        };
+
+        write!(
+            out,
+            "{}\n    {{\"string\": \"{}\", \"error\": \"{}\"}}",
+            if i == 0 { "" } else { "," },
+            string,
+            error
+        )
+        .expect("writing to a string to succeed");
+    }
+
+    out.push_str("\n  ]\n}\n");
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::{assert_roundtrip, test_vectors};
+    use crate::{Error, Generic, IdBytes, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    struct Small;
+
+    impl Type for Small {
+        const PREFIX: &'static str = "small";
+
+        fn validate(bytes: &IdBytes) -> Result<(), Error> {
+            match bytes[..8] == [0; 8] {
+                true => Ok(()),
+                false => Err(Error::InvalidData),
+            }
+        }
+    }
+
+    #[test]
+    fn vectors_parse() {
+        let vectors = test_vectors();
+
+        assert!(vectors.contains(
+            r#"{"prefix": "user", "hex": "02d950c2a86ff0c1329817c6a04c2c53", "string": "user_02v58c5a3fy30k560qrtg4rb2k"}"#
+        ));
+        assert!(vectors
+            .contains(r#"{"string": "user_02v58c5a3fy30k560qrtg4rb2l", "error": "InvalidData"}"#));
+
+        for (prefix, value) in super::VECTORS {
+            let id = Generic::new(prefix, value.to_be_bytes()).unwrap();
+            assert!(vectors.contains(&format!("\"string\": \"{}\"", id)));
+        }
+    }
+
+    #[test]
+    fn roundtrip_harness() {
+        assert_roundtrip::<User>();
+        assert_roundtrip::<Small>();
+    }
+}
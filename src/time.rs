@@ -0,0 +1,96 @@
+use crate::{Id, Type};
+use std::time::{Duration, SystemTime};
+
+/// Number of high bits holding the millisecond timestamp in time-ordered identifiers.
+///
+/// This matches the layout of ULIDs and UUIDv7.
+pub(crate) const TIMESTAMP_BITS: u32 = 48;
+
+/// Get the number of milliseconds since the configured epoch, clamped to the timestamp
+/// range.
+pub(crate) fn to_millis(time: SystemTime) -> u64 {
+    crate::config::current().to_millis(time)
+}
+
+/// Get the time a number of milliseconds after the configured epoch.
+pub(crate) fn to_time(millis: u64) -> SystemTime {
+    crate::config::current().to_time(millis)
+}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Get the creation time of this time-ordered identifier, from its top 48 bits.
+    ///
+    /// The result is only meaningful for identifiers with a timestamp, such as those from
+    /// the `Timestamped` generator or imported with `Id::from_snowflake`.
+    pub fn created_at(self) -> SystemTime {
+        to_time((self.to_u128() >> (128 - TIMESTAMP_BITS)) as u64)
+    }
+
+    /// Get the number of the time bucket this time-ordered identifier was created in.
+    ///
+    /// Buckets are consecutive windows of the provided width starting at the epoch, the
+    /// Unix epoch unless [configured](crate::Config::epoch), so with a width of one hour
+    /// the bucket is the number of hours since the epoch.
+    /// Bucket numbers are stable, and can be used directly as partition keys for tables
+    /// or topics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is shorter than a millisecond.
+    pub fn time_bucket(self, width: Duration) -> u64 {
+        let width = width.as_millis();
+        assert!(width > 0, "bucket width to be at least a millisecond");
+
+        ((self.to_u128() >> (128 - TIMESTAMP_BITS)) / width) as u64
+    }
+
+    /// Get the smallest time-ordered `Id<T>` created at the provided time.
+    ///
+    /// Time-ordered identifiers store the milliseconds since the epoch in their top 48
+    /// bits, so a time window can be queried with
+    /// `id >= Id::min_for_timestamp(start) AND id < Id::min_for_timestamp(end)`.
+    /// Times outside the representable range are clamped.
+    pub fn min_for_timestamp(time: SystemTime) -> Self {
+        Self::from((to_millis(time) as u128) << (128 - TIMESTAMP_BITS))
+    }
+
+    /// Get the largest time-ordered `Id<T>` created at the provided time.
+    ///
+    /// See [`Id::min_for_timestamp`] for the layout of time-ordered identifiers.
+    pub fn max_for_timestamp(time: SystemTime) -> Self {
+        Self::from(Self::min_for_timestamp(time).to_u128() | (u128::MAX >> TIMESTAMP_BITS))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    struct Event;
+
+    impl Type for Event {
+        const PREFIX: &'static str = "evt";
+    }
+
+    #[test]
+    fn timestamp_bounds() {
+        let time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        let min = Id::<Event>::min_for_timestamp(time);
+        let max = Id::<Event>::max_for_timestamp(time);
+
+        assert_eq!(min.to_u128() >> 80, 1_700_000_000_000);
+        assert_eq!(max.to_u128() >> 80, 1_700_000_000_000);
+        assert_eq!(
+            max.to_u128() + 1,
+            Id::<Event>::min_for_timestamp(time + Duration::from_millis(1)).to_u128()
+        );
+        assert_eq!(
+            Id::<Event>::min_for_timestamp(UNIX_EPOCH - Duration::from_secs(1)).to_u128(),
+            0
+        );
+        assert_eq!(min.created_at(), time);
+        assert_eq!(max.created_at(), time);
+        assert_eq!(max.time_bucket(Duration::from_secs(3600)), 472_222);
+    }
+}
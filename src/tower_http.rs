@@ -0,0 +1,55 @@
+//! Generating request ids for `tower_http::request_id`.
+//!
+//! ```ignore
+//! let service = ServiceBuilder::new()
+//!     .set_x_request_id(MakeSouvenirRequestId::new())
+//!     .propagate_x_request_id()
+//!     .service(handler);
+//! ```
+
+use crate::{Id, Type};
+use http::{HeaderValue, Request};
+use std::marker::PhantomData;
+use tower_http::request_id::MakeRequestId;
+
+/// Marker type for request identifiers, rendered as `req_...`.
+pub struct RequestId;
+
+impl Type for RequestId {
+    const PREFIX: &'static str = "req";
+}
+
+/// A `MakeRequestId` that generates random `Id<T>`s, `Id<RequestId>` by default.
+pub struct MakeSouvenirRequestId<T: Type = RequestId> {
+    marker: PhantomData<fn() -> T>,
+}
+
+impl MakeSouvenirRequestId {
+    /// Create a `MakeSouvenirRequestId` generating `Id<RequestId>`s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Type> Default for MakeSouvenirRequestId<T> {
+    fn default() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Type> Clone for MakeSouvenirRequestId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Type> Copy for MakeSouvenirRequestId<T> {}
+
+impl<T: Type> MakeRequestId for MakeSouvenirRequestId<T> {
+    fn make_request_id<B>(&mut self, _: &Request<B>) -> Option<tower_http::request_id::RequestId> {
+        let value = HeaderValue::from_str(&Id::<T>::random().to_string()).ok()?;
+        Some(tower_http::request_id::RequestId::new(value))
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{Id, Type};
+use ts_rs::{Config, TS};
+
+// Rendered inline as a template literal type, e.g. `user_${string}` for `Id<User>`.
+impl<T: Type> TS for Id<T> {
+    type WithoutGenerics = Self;
+    type OptionInnerType = Self;
+
+    fn name(_: &Config) -> String {
+        format!("`{}_${{string}}`", T::PREFIX)
+    }
+
+    fn inline(cfg: &Config) -> String {
+        Self::name(cfg)
+    }
+}
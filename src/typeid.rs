@@ -0,0 +1,136 @@
+//! Compatibility with the [TypeID](https://github.com/jetify-com/typeid) specification.
+//!
+//! A TypeID is a lowercase prefix and the 26 character base32 encoding of a UUID, which is
+//! the same alphabet and encoding souvenir uses for identifier values.
+//! `user_01h455vb4pex5vsknk084sn02q` is both a TypeID and an `Id<User>`. The TypeID mode
+//! differs in its edge cases: prefixes may contain underscores, except in a `Generic`, an
+//! empty prefix has no separator, and environment tags are never written or accepted.
+//!
+//! TypeIDs should carry a UUIDv7. With the `uuid` feature, convert time-ordered
+//! identifiers with `Id::to_uuid_v7` before formatting them for TypeID systems.
+#[cfg(feature = "alloc")]
+use crate::encoding::stringify_base32;
+#[cfg(feature = "alloc")]
+use crate::Generic;
+use crate::{encoding::parse_base32, Error, Id, Segment, Type};
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+/// The maximum length of a TypeID prefix.
+const MAX_PREFIX_LENGTH: usize = 63;
+
+/// Split a TypeID into its prefix and suffix, checking the prefix against the specification.
+fn split(value: &str) -> Result<(&str, &str), Error> {
+    let (prefix, suffix) = match value.rsplit_once('_') {
+        Some(("", _)) => return Err(Error::InvalidData),
+        Some(parts) => parts,
+        None => ("", value),
+    };
+
+    let valid = prefix.len() <= MAX_PREFIX_LENGTH
+        && prefix.bytes().all(|b| b.is_ascii_lowercase() || b == b'_')
+        && !prefix.starts_with('_')
+        && !prefix.ends_with('_');
+
+    if !valid {
+        return Err(Error::InvalidData);
+    }
+
+    Ok((prefix, suffix))
+}
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Attempt to parse the provided TypeID into an `Id<T>`.
+    pub fn parse_type_id(value: &str) -> Result<Self, Error> {
+        let (prefix, suffix) = split(value)?;
+
+        if prefix != T::PREFIX {
+            return Err(Error::PrefixMismatch {
+                name: T::NAME,
+                expected: T::PREFIX,
+                actual: Segment::new(prefix),
+            });
+        }
+
+        Self::new_checked(parse_base32(suffix)?)
+    }
+
+    /// Format this identifier as a TypeID.
+    ///
+    /// The result is only a valid TypeID if `T::PREFIX` follows the specification, with at
+    /// most 63 lowercase ASCII letters and underscores.
+    #[cfg(feature = "alloc")]
+    pub fn to_type_id(self) -> String {
+        let value = stringify_base32(self.to_bytes()).expect("id value to stringify correctly");
+
+        match T::PREFIX {
+            "" => value,
+            prefix => format!("{}_{}", prefix, value),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Generic {
+    /// Attempt to parse the provided TypeID into a `Generic`.
+    ///
+    /// TypeID prefixes may contain underscores, but a `Generic` with one would not parse
+    /// back from its `Display` form, so those prefixes return [`Error::InvalidPrefix`].
+    pub fn parse_type_id(value: &str) -> Result<Self, Error> {
+        let (prefix, suffix) = split(value)?;
+
+        if prefix.contains('_') {
+            return Err(Error::InvalidPrefix);
+        }
+
+        Self::new(prefix, parse_base32(suffix)?)
+    }
+
+    /// Format this identifier as a TypeID.
+    ///
+    /// See [`Id::to_type_id`] for the prefixes which form valid TypeIDs.
+    pub fn to_type_id(&self) -> String {
+        let value = stringify_base32(*self.as_bytes()).expect("id value to stringify correctly");
+
+        match self.prefix() {
+            "" => value,
+            prefix => format!("{}_{}", prefix, value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Error, Generic, Id, Type};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn type_id_round_trip() {
+        let id = Id::<User>::parse_type_id("user_01h455vb4pex5vsknk084sn02q").unwrap();
+        assert_eq!(id.to_type_id(), "user_01h455vb4pex5vsknk084sn02q");
+
+        let id = Generic::parse_type_id("key_01h455vb4pex5vsknk084sn02q").unwrap();
+        assert_eq!(id.prefix(), "key");
+        assert_eq!(id.to_type_id(), "key_01h455vb4pex5vsknk084sn02q");
+        assert_eq!(Generic::parse(&id.to_string()).unwrap(), id);
+
+        assert!(matches!(
+            Generic::parse_type_id("api_key_01h455vb4pex5vsknk084sn02q"),
+            Err(Error::InvalidPrefix)
+        ));
+
+        let id = Generic::parse_type_id("01h455vb4pex5vsknk084sn02q").unwrap();
+        assert_eq!(id.prefix(), "");
+        assert_eq!(id.to_type_id(), "01h455vb4pex5vsknk084sn02q");
+
+        assert!(Generic::parse_type_id("_01h455vb4pex5vsknk084sn02q").is_err());
+        assert!(Generic::parse_type_id("User_01h455vb4pex5vsknk084sn02q").is_err());
+        assert!(Generic::parse_type_id("user_01H455VB4PEX5VSKNK084SN02Q").is_err());
+        assert!(Generic::parse_type_id("user_81h455vb4pex5vsknk084sn02q").is_err());
+    }
+}
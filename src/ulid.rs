@@ -0,0 +1,58 @@
+//! Conversions between identifiers and ULIDs.
+//!
+//! ULIDs are 128 bits with a 48-bit millisecond timestamp in the top bits, the same
+//! layout as time-ordered identifiers, so an `Id<T>` holds the value of a ULID unchanged.
+//! Converting in either direction is lossless and keeps the timestamp and sort order, and
+//! the identifier value is the ULID string in lowercase: `01ARZ3NDEKTSV4RRFFQ69G5FAV`
+//! becomes `user_01arz3ndektsv4rrffq69g5fav`.
+//!
+//! New identifiers with the ULID layout are created with the [`Timestamped`](crate::Timestamped) generator.
+
+use crate::{Id, Type};
+use ulid::Ulid;
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Convert this identifier into a ULID with the same value.
+    pub fn to_ulid(self) -> Ulid {
+        Ulid(self.to_u128())
+    }
+
+    /// Create an `Id<T>` holding the value of the provided ULID, without checking it with
+    /// [`Type::validate`].
+    pub fn from_ulid(ulid: Ulid) -> Self {
+        Self::from(ulid.0)
+    }
+}
+
+impl<T: Type + ?Sized> From<Ulid> for Id<T> {
+    fn from(value: Ulid) -> Self {
+        Self::from_ulid(value)
+    }
+}
+
+impl<T: Type + ?Sized> From<Id<T>> for Ulid {
+    fn from(value: Id<T>) -> Self {
+        value.to_ulid()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type};
+    use ulid::Ulid;
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn ulid_round_trip() {
+        let ulid = Ulid::from_string("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+        let id = Id::<User>::from_ulid(ulid);
+
+        assert_eq!(id.to_string(), "user_01arz3ndektsv4rrffq69g5fav");
+        assert_eq!(id.to_ulid(), ulid);
+    }
+}
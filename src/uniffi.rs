@@ -0,0 +1,103 @@
+//! Kotlin and Swift bindings through UniFFI.
+//!
+//! The exported functions work on runtime prefixes, like [`Generic`]. Bindings are
+//! generated from the compiled library with `uniffi-bindgen`:
+//!
+//! ```kotlin
+//! val id = generateId("user")
+//! val parsed = parseId(id)
+//! check(validateId(id, "user"))
+//! check(formatId(parsed.prefix, parsed.bytes) == id)
+//! ```
+
+use crate::{Error, Generic};
+use std::fmt::{Display, Formatter};
+
+/// An identifier split into its prefix and 16 bytes.
+#[derive(Clone, Debug, PartialEq, Eq, ::uniffi::Record)]
+pub struct ParsedId {
+    pub prefix: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Error returned to foreign code when an identifier is invalid.
+#[derive(Debug, ::uniffi::Error)]
+pub enum IdError {
+    /// The identifier was malformed.
+    InvalidData,
+    /// The prefix was not up to 63 ASCII letters and digits.
+    InvalidPrefix,
+    /// The identifier had a different prefix than expected.
+    PrefixMismatch { expected: String, actual: String },
+    /// The identifier data was not 16 bytes long.
+    InvalidLength { length: u64 },
+}
+
+impl Display for IdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidData => write!(f, "{}", Error::InvalidData),
+            Self::InvalidPrefix => write!(f, "{}", Error::InvalidPrefix),
+            Self::PrefixMismatch { expected, actual } => {
+                write!(f, "expected prefix `{}`, found `{}`", expected, actual)
+            }
+            Self::InvalidLength { length } => {
+                write!(f, "identifier data must be 16 bytes, found {}", length)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IdError {}
+
+impl From<Error> for IdError {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::InvalidPrefix => Self::InvalidPrefix,
+            _ => Self::InvalidData,
+        }
+    }
+}
+
+/// Generate a new random identifier with the provided prefix.
+#[::uniffi::export]
+pub fn generate_id(prefix: String) -> Result<String, IdError> {
+    Ok(Generic::random(prefix)?.to_string())
+}
+
+/// Parse an identifier, optionally requiring the provided prefix.
+#[::uniffi::export(default(prefix = None))]
+pub fn parse_id(value: String, prefix: Option<String>) -> Result<ParsedId, IdError> {
+    let id = Generic::parse(&value)?;
+
+    match prefix {
+        Some(prefix) if prefix != id.prefix() => Err(IdError::PrefixMismatch {
+            expected: prefix,
+            actual: id.prefix().to_owned(),
+        }),
+        _ => Ok(ParsedId {
+            prefix: id.prefix().to_owned(),
+            bytes: id.as_bytes().to_vec(),
+        }),
+    }
+}
+
+/// Format an identifier from its prefix and 16 bytes.
+#[::uniffi::export]
+pub fn format_id(prefix: String, bytes: Vec<u8>) -> Result<String, IdError> {
+    let value = bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| IdError::InvalidLength {
+            length: bytes.len() as u64,
+        })?;
+
+    Ok(Generic::new(prefix, value)?.to_string())
+}
+
+/// Test to see if the provided string is a valid identifier, optionally with the
+/// provided prefix.
+#[::uniffi::export(default(prefix = None))]
+pub fn validate_id(value: String, prefix: Option<String>) -> bool {
+    parse_id(value, prefix).is_ok()
+}
@@ -0,0 +1,28 @@
+use crate::encoding::{description, pattern, EXAMPLE};
+use crate::{Id, Type};
+use std::borrow::Cow;
+use utoipa::__dev::ComposeSchema;
+use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaType, Type as SchemaKind};
+use utoipa::openapi::RefOr;
+use utoipa::ToSchema;
+
+// `PartialSchema` comes from utoipa's blanket impl over `ComposeSchema`, which is also what
+// `#[derive(ToSchema)]` requires for generic field types such as `Id<User>`. The derive names
+// the schema after the type argument (e.g. `Id_User`), so `T` must implement `ToSchema` there.
+// Registered on its own, each `Id<T>` is named after its prefix, matching the schemars name.
+impl<T: Type> ComposeSchema for Id<T> {
+    fn compose(_: Vec<RefOr<Schema>>) -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(SchemaType::Type(SchemaKind::String))
+            .description(Some(description::<T>()))
+            .pattern(Some(pattern(T::PREFIX)))
+            .examples([Id::<T>::new(EXAMPLE).to_string()])
+            .into()
+    }
+}
+
+impl<T: Type> ToSchema for Id<T> {
+    fn name() -> Cow<'static, str> {
+        format!("Id_{}", T::PREFIX).into()
+    }
+}
@@ -0,0 +1,102 @@
+//! Conversions between identifiers and UUIDs.
+//!
+//! Identifiers and UUIDs are both 16 bytes, so an `Id<T>` embeds a UUID by holding its
+//! bytes unchanged, in the same order. Converting in either direction is lossless, and
+//! UUIDs which sort by their bytes, such as UUIDv7, convert to identifiers which sort the
+//! same way. Because nothing is lost, there is no `from_uuid_lossy`; use
+//! [`Id::try_from_uuid`] to also check the value with [`Type::validate`].
+//!
+//! Time-ordered identifiers share their timestamp layout with UUIDv7, but not its version
+//! and variant bits; use [`Id::to_uuid_v7`] when a system only accepts UUIDv7.
+
+use crate::{Error, Id, Type};
+use uuid::{Builder, Uuid, Variant, Version};
+
+impl<T: Type + ?Sized> Id<T> {
+    /// Convert this identifier into a UUID with the same bytes.
+    pub fn to_uuid(self) -> Uuid {
+        Uuid::from_bytes(self.to_bytes())
+    }
+
+    /// Create an `Id<T>` holding the bytes of the provided UUID, without checking them with
+    /// [`Type::validate`]; see [`Id::try_from_uuid`].
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self::new(*uuid.as_bytes())
+    }
+
+    /// Create an `Id<T>` holding the bytes of the provided UUID, checking them with
+    /// [`Type::validate`].
+    pub fn try_from_uuid(uuid: Uuid) -> Result<Self, Error> {
+        Self::new_checked(uuid.into_bytes())
+    }
+
+    /// Convert this time-ordered identifier into a UUIDv7 with the same timestamp.
+    ///
+    /// The 48-bit millisecond timestamp is kept, so identifiers created in different
+    /// milliseconds keep their order and creation time. The version and variant bits
+    /// overwrite 6 of the random bits, so this is lossy, and identifiers from the same
+    /// millisecond may sort differently afterwards.
+    pub fn to_uuid_v7(self) -> Uuid {
+        Builder::from_bytes(self.to_bytes())
+            .with_version(Version::SortRand)
+            .with_variant(Variant::RFC4122)
+            .into_uuid()
+    }
+
+    /// Create a time-ordered `Id<T>` from a UUIDv7, keeping its timestamp.
+    ///
+    /// The identifier holds the bytes of the UUID unchanged, so converting it back with
+    /// [`Id::to_uuid_v7`] returns the same UUID. Returns [`Error::InvalidData`] if the UUID
+    /// is not version 7; the value is not checked with [`Type::validate`].
+    pub fn from_uuid_v7(uuid: Uuid) -> Result<Self, Error> {
+        match uuid.get_version() {
+            Some(Version::SortRand) => Ok(Self::from_uuid(uuid)),
+            _ => Err(Error::InvalidData),
+        }
+    }
+}
+
+impl<T: Type + ?Sized> From<Uuid> for Id<T> {
+    fn from(value: Uuid) -> Self {
+        Self::from_uuid(value)
+    }
+}
+
+impl<T: Type + ?Sized> From<Id<T>> for Uuid {
+    fn from(value: Id<T>) -> Self {
+        value.to_uuid()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type};
+    use uuid::{Uuid, Version};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn uuid_round_trip() {
+        let uuid = Uuid::from_u128(0x02d950c2_a86f_f0c1_3298_17c6a04c2c53);
+        let id = Id::<User>::from_uuid(uuid);
+
+        assert_eq!(id.to_string(), "user_02v58c5a3fy30k560qrtg4rb2k");
+        assert_eq!(id.to_uuid(), uuid);
+        assert_eq!(Id::<User>::try_from_uuid(uuid).unwrap(), id);
+    }
+
+    #[test]
+    fn uuid_v7_timestamp() {
+        let id = Id::<User>::from(1_700_000_000_000u128 << 80 | u128::MAX >> 48);
+        let uuid = id.to_uuid_v7();
+
+        assert_eq!(uuid.get_version(), Some(Version::SortRand));
+        assert_eq!(uuid.as_u128() >> 80, 1_700_000_000_000);
+        assert_eq!(Id::<User>::from_uuid_v7(uuid).unwrap().to_uuid_v7(), uuid);
+        assert!(Id::<User>::from_uuid_v7(Uuid::nil()).is_err());
+    }
+}
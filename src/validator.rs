@@ -0,0 +1,73 @@
+//! Validating identifiers with the `validator` crate.
+//!
+//! Request types that keep identifiers as raw strings can check them with
+//! [`validate_id`] as a custom validation function:
+//!
+//! ```ignore
+//! #[derive(Validate)]
+//! struct CreatePost {
+//!     #[validate(custom(function = "souvenir::validator::validate_id::<User>"))]
+//!     author: String,
+//! }
+//! ```
+//!
+//! [`ValidateId`] offers the same check as a method on strings, in the style of
+//! `validator`'s own `ValidateEmail` and `ValidateUrl` traits.
+
+use crate::{Id, Type};
+use std::borrow::Cow;
+use validator::ValidationError;
+
+/// Validate that `value` is a well-formed `Id<T>`.
+///
+/// Failures use the `souvenir_id` error code and carry the offending `value` and the
+/// `expected` prefix as parameters.
+pub fn validate_id<T: Type + ?Sized>(value: &str) -> Result<(), ValidationError> {
+    Id::<T>::parse(value).map(|_| ()).map_err(|err| {
+        let mut error = ValidationError::new("souvenir_id").with_message(Cow::Owned(format!(
+            "invalid `{}` identifier: {}",
+            T::PREFIX,
+            err
+        )));
+
+        error.add_param(Cow::Borrowed("value"), &value);
+        error.add_param(Cow::Borrowed("expected"), &T::PREFIX);
+        error
+    })
+}
+
+/// Types that can be checked for holding a well-formed `Id<T>`.
+pub trait ValidateId {
+    /// Test whether the value is a valid `Id<T>`.
+    fn validate_id<T: Type + ?Sized>(&self) -> bool;
+}
+
+impl ValidateId for str {
+    fn validate_id<T: Type + ?Sized>(&self) -> bool {
+        Id::<T>::test(self)
+    }
+}
+
+impl ValidateId for String {
+    fn validate_id<T: Type + ?Sized>(&self) -> bool {
+        self.as_str().validate_id::<T>()
+    }
+}
+
+impl ValidateId for Cow<'_, str> {
+    fn validate_id<T: Type + ?Sized>(&self) -> bool {
+        self.as_ref().validate_id::<T>()
+    }
+}
+
+impl<V: ValidateId + ?Sized> ValidateId for &V {
+    fn validate_id<T: Type + ?Sized>(&self) -> bool {
+        (**self).validate_id::<T>()
+    }
+}
+
+impl<V: ValidateId> ValidateId for Option<V> {
+    fn validate_id<T: Type + ?Sized>(&self) -> bool {
+        self.as_ref().is_none_or(|value| value.validate_id::<T>())
+    }
+}
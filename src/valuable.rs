@@ -0,0 +1,28 @@
+use crate::encoding::stringify_base32;
+use crate::{Id, Type};
+use valuable::{Fields, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit};
+
+const FIELDS: &[NamedField<'static>] = &[NamedField::new("prefix"), NamedField::new("value")];
+
+// Recorded as `Id { prefix, value }` so structured backends keep the prefix separately, e.g.
+// `tracing::info!(user = tracing::field::valuable(&id))` with `tracing_unstable` enabled.
+impl<T: Type> Valuable for Id<T> {
+    fn as_value(&self) -> Value<'_> {
+        Value::Structable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        let value = stringify_base32(*self.as_bytes()).expect("id value to stringify correctly");
+
+        visit.visit_named_fields(&NamedValues::new(
+            FIELDS,
+            &[Value::String(T::PREFIX), Value::String(&value)],
+        ));
+    }
+}
+
+impl<T: Type> Structable for Id<T> {
+    fn definition(&self) -> StructDef<'_> {
+        StructDef::new_static("Id", Fields::Named(FIELDS))
+    }
+}
@@ -0,0 +1,175 @@
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use crate::{Error, Id, Type};
+
+/// An identifier paired with the version of the record it identifies, for optimistic
+/// locking.
+///
+/// Versioned identifiers are formatted as the identifier followed by `.v` and the version
+/// in decimal, like `user_02v58c5a3fy30k560qrtg4rb2k.v3`, so they can be handed to
+/// clients as a single token and checked against the stored version when sent back.
+/// They are ordered by identifier, then by version.
+pub struct VersionedId<T: Type + ?Sized> {
+    id: Id<T>,
+    version: u64,
+}
+
+impl<T: Type + ?Sized> VersionedId<T> {
+    /// Create a new versioned identifier.
+    pub fn new(id: Id<T>, version: u64) -> Self {
+        Self { id, version }
+    }
+
+    /// Get the identifier.
+    pub fn id(self) -> Id<T> {
+        self.id
+    }
+
+    /// Get the version.
+    pub fn version(self) -> u64 {
+        self.version
+    }
+
+    /// Get the versioned identifier for the next version of the same record.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the version is `u64::MAX`.
+    pub fn next(self) -> Self {
+        let version = self
+            .version
+            .checked_add(1)
+            .expect("version to not overflow");
+        Self::new(self.id, version)
+    }
+
+    /// Split this versioned identifier into its identifier and version.
+    pub fn into_parts(self) -> (Id<T>, u64) {
+        (self.id, self.version)
+    }
+
+    /// Test to see if the provided string is a valid `VersionedId<T>`.
+    pub fn test(value: &str) -> bool {
+        Self::parse(value).is_ok()
+    }
+
+    /// Attempt to parse the provided string into a `VersionedId<T>`.
+    ///
+    /// Versions must be written without leading zeros, so each versioned identifier has a
+    /// single string form.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let (id, version) = value.rsplit_once(".v").ok_or(Error::InvalidData)?;
+
+        let canonical = !version.is_empty()
+            && version.bytes().all(|b| b.is_ascii_digit())
+            && (version == "0" || !version.starts_with('0'));
+
+        if !canonical {
+            return Err(Error::InvalidData);
+        }
+
+        let version = version.parse().map_err(|_| Error::InvalidData)?;
+
+        Ok(Self::new(Id::parse(id)?, version))
+    }
+}
+
+impl<T: Type + ?Sized> Copy for VersionedId<T> {}
+
+impl<T: Type + ?Sized> Clone for VersionedId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Type + ?Sized> PartialEq for VersionedId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.version == other.version
+    }
+}
+
+impl<T: Type + ?Sized> Eq for VersionedId<T> {}
+
+impl<T: Type + ?Sized> PartialOrd for VersionedId<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Type + ?Sized> Ord for VersionedId<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.id, self.version).cmp(&(other.id, other.version))
+    }
+}
+
+impl<T: Type + ?Sized> Hash for VersionedId<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.version.hash(state);
+    }
+}
+
+impl<T: Type + ?Sized> Debug for VersionedId<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl<T: Type + ?Sized> Display for VersionedId<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.v{}", self.id, self.version)
+    }
+}
+
+impl<T: Type + ?Sized> FromStr for VersionedId<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl<T: Type + ?Sized> From<(Id<T>, u64)> for VersionedId<T> {
+    fn from((id, version): (Id<T>, u64)) -> Self {
+        Self::new(id, version)
+    }
+}
+
+impl<T: Type + ?Sized> From<VersionedId<T>> for (Id<T>, u64) {
+    fn from(value: VersionedId<T>) -> Self {
+        value.into_parts()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, Type, VersionedId};
+
+    struct User;
+
+    impl Type for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn versioned_round_trip() {
+        let id: Id<User> = Id::parse("user_02v58c5a3fy30k560qrtg4rb2k").unwrap();
+        let versioned = VersionedId::new(id, 3);
+
+        assert_eq!(versioned.to_string(), "user_02v58c5a3fy30k560qrtg4rb2k.v3");
+        assert_eq!(
+            VersionedId::parse(&versioned.to_string()).unwrap(),
+            versioned
+        );
+        assert_eq!(versioned.next().version(), 4);
+        assert!(versioned < versioned.next());
+
+        assert!(VersionedId::<User>::parse("user_02v58c5a3fy30k560qrtg4rb2k").is_err());
+        assert!(VersionedId::<User>::parse("user_02v58c5a3fy30k560qrtg4rb2k.v").is_err());
+        assert!(VersionedId::<User>::parse("user_02v58c5a3fy30k560qrtg4rb2k.v03").is_err());
+        assert!(VersionedId::<User>::parse("user_02v58c5a3fy30k560qrtg4rb2k.v+3").is_err());
+        assert!(VersionedId::<User>::parse("user_02v58c5a3fy30k560qrtg4rb2k.v0").is_ok());
+    }
+}
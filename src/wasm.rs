@@ -0,0 +1,89 @@
+//! JavaScript bindings through `wasm-bindgen`.
+//!
+//! The exported `Id` class wraps a [`Generic`] identifier, since prefixes are only known
+//! at runtime on the JavaScript side:
+//!
+//! ```js
+//! import { Id } from "souvenir";
+//!
+//! const id = new Id("user");
+//! console.log(id.prefix, id.toString());
+//!
+//! const parsed = Id.parse("user_02v58c5a3fy30k560qrtg4rb2k");
+//! Id.test("user_02v58c5a3fy30k560qrtg4rb2k", "user"); // true
+//! ```
+
+use crate::Generic;
+use wasm_bindgen::prelude::*;
+
+/// A prefixed identifier exported to JavaScript.
+#[wasm_bindgen(js_name = Id)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WasmId {
+    inner: Generic,
+}
+
+#[wasm_bindgen(js_class = Id)]
+impl WasmId {
+    /// Generate a new random identifier with the provided prefix.
+    #[wasm_bindgen(constructor)]
+    pub fn new(prefix: &str) -> Result<WasmId, JsError> {
+        Generic::random(prefix)
+            .map(|inner| Self { inner })
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Create an identifier with the provided prefix from its 16 bytes.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(prefix: &str, bytes: &[u8]) -> Result<WasmId, JsError> {
+        let value = bytes
+            .try_into()
+            .map_err(|_| JsError::new("identifier data must be 16 bytes"))?;
+
+        Generic::new(prefix, value)
+            .map(|inner| Self { inner })
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Parse an identifier, throwing an error if it is malformed.
+    pub fn parse(value: &str) -> Result<WasmId, JsError> {
+        Generic::parse(value)
+            .map(|inner| Self { inner })
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Test to see if the provided string is a valid identifier, optionally with
+    /// the provided prefix.
+    pub fn test(value: &str, prefix: Option<String>) -> bool {
+        Generic::parse(value).is_ok_and(|id| prefix.is_none_or(|prefix| id.prefix() == prefix))
+    }
+
+    /// Get the prefix of this identifier.
+    #[wasm_bindgen(getter)]
+    pub fn prefix(&self) -> String {
+        self.inner.prefix().to_owned()
+    }
+
+    /// Get the 16 bytes of this identifier.
+    pub fn bytes(&self) -> Vec<u8> {
+        self.inner.as_bytes().to_vec()
+    }
+
+    /// Format this identifier as a string.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.inner.to_string()
+    }
+}
+
+impl From<Generic> for WasmId {
+    fn from(value: Generic) -> Self {
+        Self { inner: value }
+    }
+}
+
+impl From<WasmId> for Generic {
+    fn from(value: WasmId) -> Self {
+        value.inner
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{Id, SecretId, Type};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+impl<T: Type + ?Sized> Zeroize for Id<T> {
+    fn zeroize(&mut self) {
+        self.as_bytes_mut().zeroize();
+    }
+}
+
+impl<T: Type + ?Sized> Zeroize for SecretId<T> {
+    fn zeroize(&mut self) {
+        self.expose_mut().zeroize();
+    }
+}
+
+// `Id<T>` is `Copy`, so only the `SecretId<T>` wrapper can scrub itself on drop.
+impl<T: Type + ?Sized> Drop for SecretId<T> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<T: Type + ?Sized> ZeroizeOnDrop for SecretId<T> {}